@@ -0,0 +1,203 @@
+//! A priority queue implemented with a binary heap stored in a `RotatedVec`.
+//!
+//! The heap only pushes and pops at the back of the vector, and sifting only
+//! needs constant-time indexed access, both of which `RotatedVec` provides, so
+//! this performs like `std::collections::BinaryHeap`.
+
+use core::fmt::Debug;
+use core::mem;
+
+use crate::RotatedVec;
+
+/// A priority queue implemented with a binary heap.
+///
+/// This will be a max-heap.
+///
+/// # Examples
+///
+/// ```
+/// use rotated_vec::binary_heap::BinaryHeap;
+///
+/// let mut heap = BinaryHeap::new();
+/// heap.push(1);
+/// heap.push(5);
+/// heap.push(2);
+///
+/// assert_eq!(heap.peek(), Some(&5));
+/// assert_eq!(heap.len(), 3);
+///
+/// assert_eq!(heap.pop(), Some(5));
+/// assert_eq!(heap.pop(), Some(2));
+/// assert_eq!(heap.pop(), Some(1));
+/// assert_eq!(heap.pop(), None);
+/// ```
+#[derive(Clone, Debug)]
+pub struct BinaryHeap<T> {
+    data: RotatedVec<T>,
+}
+
+impl<T> BinaryHeap<T>
+where
+    T: Copy + Default + Debug + Ord,
+{
+    /// Creates an empty `BinaryHeap` as a max-heap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::binary_heap::BinaryHeap;
+    ///
+    /// let mut heap = BinaryHeap::new();
+    /// heap.push(4);
+    /// ```
+    pub fn new() -> Self {
+        BinaryHeap {
+            data: RotatedVec::new(),
+        }
+    }
+
+    /// Returns the greatest item in the binary heap, or `None` if it is empty.
+    ///
+    /// This is a constant-time operation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::binary_heap::BinaryHeap;
+    ///
+    /// let mut heap = BinaryHeap::new();
+    /// assert_eq!(heap.peek(), None);
+    ///
+    /// heap.push(1);
+    /// heap.push(5);
+    /// heap.push(2);
+    /// assert_eq!(heap.peek(), Some(&5));
+    /// ```
+    pub fn peek(&self) -> Option<&T> {
+        self.data.get(0)
+    }
+
+    /// Pushes an item onto the binary heap.
+    ///
+    /// This is an `O(log n)` operation, plus the amortized constant cost of
+    /// growing the backing vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::binary_heap::BinaryHeap;
+    ///
+    /// let mut heap = BinaryHeap::new();
+    /// heap.push(3);
+    /// heap.push(5);
+    /// heap.push(1);
+    ///
+    /// assert_eq!(heap.len(), 3);
+    /// assert_eq!(heap.peek(), Some(&5));
+    /// ```
+    pub fn push(&mut self, item: T) {
+        self.data.push(item);
+        let mut child = self.data.len() - 1;
+        while child > 0 {
+            let parent = (child - 1) / 2;
+            let (child_ref, parent_ref) = self.data.pair_mut(child, parent).unwrap();
+            if *child_ref <= *parent_ref {
+                break;
+            }
+            mem::swap(child_ref, parent_ref);
+            child = parent;
+        }
+    }
+
+    /// Removes the greatest item from the binary heap and returns it, or
+    /// `None` if it is empty.
+    ///
+    /// This is an `O(log n)` operation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::binary_heap::BinaryHeap;
+    ///
+    /// let mut heap = BinaryHeap::new();
+    /// heap.push(1);
+    /// heap.push(3);
+    ///
+    /// assert_eq!(heap.pop(), Some(3));
+    /// assert_eq!(heap.pop(), Some(1));
+    /// assert_eq!(heap.pop(), None);
+    /// ```
+    pub fn pop(&mut self) -> Option<T> {
+        let len = self.data.len();
+        if len == 0 {
+            return None;
+        }
+        self.data.swap(0, len - 1);
+        let top = self.data.pop();
+        let len = len - 1;
+        let mut parent = 0;
+        loop {
+            let left = 2 * parent + 1;
+            if left >= len {
+                break;
+            }
+            let right = left + 1;
+            let child = if right < len && self.data[right] > self.data[left] {
+                right
+            } else {
+                left
+            };
+            let (parent_ref, child_ref) = self.data.pair_mut(parent, child).unwrap();
+            if *parent_ref >= *child_ref {
+                break;
+            }
+            mem::swap(parent_ref, child_ref);
+            parent = child;
+        }
+        top
+    }
+
+    /// Returns the length of the binary heap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::binary_heap::BinaryHeap;
+    ///
+    /// let mut heap = BinaryHeap::new();
+    /// heap.push(1);
+    /// heap.push(3);
+    ///
+    /// assert_eq!(heap.len(), 2);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns `true` if the binary heap is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::binary_heap::BinaryHeap;
+    ///
+    /// let mut heap = BinaryHeap::new();
+    /// assert!(heap.is_empty());
+    ///
+    /// heap.push(1);
+    /// assert!(!heap.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+}
+
+impl<T> Default for BinaryHeap<T>
+where
+    T: Copy + Default + Debug + Ord,
+{
+    #[inline]
+    fn default() -> BinaryHeap<T> {
+        BinaryHeap::new()
+    }
+}
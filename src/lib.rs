@@ -5,13 +5,71 @@
 
 #![doc(html_root_url = "https://docs.rs/rotated-vec/0.1.0/rotated_vec/")]
 #![doc(html_logo_url = "https://raw.githubusercontent.com/senderista/rotated-array-set/master/img/cells.png")]
+#![cfg_attr(feature = "nightly", feature(extend_one, try_trait_v2))]
+#![cfg_attr(not(feature = "std"), no_std)]
 
-use std::mem;
-use std::cmp::{min, Ordering};
-use std::fmt::Debug;
-use std::hash::{Hash, Hasher};
-use std::iter::{DoubleEndedIterator, ExactSizeIterator, FromIterator, FusedIterator};
-use std::ops::{Index, IndexMut};
+extern crate alloc;
+
+use alloc::collections::{TryReserveError, VecDeque};
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use core::mem;
+use core::cmp::{max, min, Ordering};
+use core::fmt::{self, Debug};
+use core::hash::{Hash, Hasher};
+use core::iter::{once, Copied, DoubleEndedIterator, ExactSizeIterator, FromIterator, FusedIterator, Product, Rev, Sum, Zip};
+use core::ops::{Bound, Index, IndexMut, Range, RangeBounds};
+#[cfg(feature = "nightly")]
+use core::ops::Try;
+#[cfg(feature = "std")]
+use std::io;
+
+pub mod binary_heap;
+pub mod rotated_deque;
+
+// lets `rotated_vec!` build its backing `Vec` without requiring callers to
+// import `alloc` themselves
+#[doc(hidden)]
+pub use alloc::vec as __vec;
+
+/// Creates a [`RotatedVec`] containing the arguments.
+///
+/// `rotated_vec!` allows `RotatedVec`s to be defined with the same syntax as
+/// array expressions and `vec!`. There are two forms of this macro:
+///
+/// - Create a `RotatedVec` containing a given list of elements:
+///
+/// ```
+/// use rotated_vec::rotated_vec;
+///
+/// let v = rotated_vec![1, 2, 3];
+/// assert_eq!(v[0], 1);
+/// assert_eq!(v[1], 2);
+/// assert_eq!(v[2], 3);
+/// ```
+///
+/// - Create a `RotatedVec` from a given element and size:
+///
+/// ```
+/// use rotated_vec::rotated_vec;
+///
+/// let v = rotated_vec![1; 3];
+/// assert_eq!(v, rotated_vec![1, 1, 1]);
+/// ```
+///
+/// [`RotatedVec`]: struct.RotatedVec.html
+#[macro_export]
+macro_rules! rotated_vec {
+    () => (
+        $crate::RotatedVec::new()
+    );
+    ($elem:expr; $n:expr) => (
+        $crate::RotatedVec::from($crate::__vec![$elem; $n])
+    );
+    ($($x:expr),+ $(,)?) => (
+        $crate::RotatedVec::from($crate::__vec![$($x),+])
+    );
+}
 
 /// A dynamic array based on a 2-level rotated array.
 ///
@@ -51,10 +109,11 @@ use std::ops::{Index, IndexMut};
 ///     println!("{}", int);
 /// }
 /// ```
-#[derive(Debug, Clone)]
 pub struct RotatedVec<T> {
     data: Vec<T>,
     start_indexes: Vec<usize>,
+    #[cfg(feature = "metrics")]
+    move_count: u64,
 }
 
 /// An iterator over the items of a `RotatedVec`.
@@ -68,7 +127,8 @@ pub struct RotatedVec<T> {
 pub struct Iter<'a, T: 'a> {
     container: &'a RotatedVec<T>,
     next_index: usize,
-    next_rev_index: usize,
+    // one past the index of the element to be returned by `next_back()`
+    end_index: usize,
 }
 
 impl<'a, T> Iter<'a, T>
@@ -77,11 +137,8 @@ where
 {
     #[inline(always)]
     fn assert_invariants(&self) -> bool {
-        assert!(self.next_index <= self.container.len());
-        assert!(self.next_rev_index <= self.container.len());
-        if self.next_rev_index < self.next_index {
-            assert!(self.next_index - self.next_rev_index == 1);
-        }
+        assert!(self.next_index <= self.end_index);
+        assert!(self.end_index <= self.container.len());
         true
     }
 }
@@ -95,26 +152,287 @@ where
 /// [`iter_mut`]: struct.RotatedVec.html#method.iter_mut
 #[derive(Debug)]
 pub struct IterMut<'a, T: 'a> {
-    container: &'a mut RotatedVec<T>,
+    container: &'a RotatedVec<T>,
+    // elements are reached through this pointer rather than through `container`,
+    // so that handing out a reference to one element never invalidates the others
+    data: *mut T,
     next_index: usize,
-    next_rev_index: usize,
+    // one past the index of the element to be returned by `next_back()`
+    end_index: usize,
+    marker: PhantomData<&'a mut T>,
 }
 
+// these match the auto traits of `&'a mut [T]`, which `IterMut` effectively borrows
+unsafe impl<T: Send> Send for IterMut<'_, T> {}
+unsafe impl<T: Sync> Sync for IterMut<'_, T> {}
+
 impl<'a, T> IterMut<'a, T>
 where
     T: Copy + Default + Debug,
 {
     #[inline(always)]
     fn assert_invariants(&self) -> bool {
-        assert!(self.next_index <= self.container.len());
-        assert!(self.next_rev_index <= self.container.len());
-        if self.next_rev_index < self.next_index {
-            assert!(self.next_index - self.next_rev_index == 1);
-        }
+        assert!(self.next_index <= self.end_index);
+        assert!(self.end_index <= self.container.len());
         true
     }
 }
 
+/// A view over a contiguous logical range of a `RotatedVec`.
+///
+/// This `struct` is created by methods such as [`split_first`] on
+/// [`RotatedVec`][`RotatedVec`]. See their documentation for more.
+///
+/// [`RotatedVec`]: struct.RotatedVec.html
+/// [`split_first`]: struct.RotatedVec.html#method.split_first
+#[derive(Debug, Copy, Clone)]
+pub struct RotatedSlice<'a, T: 'a> {
+    container: &'a RotatedVec<T>,
+    start_index: usize,
+    end_index: usize,
+}
+
+impl<'a, T> RotatedSlice<'a, T>
+where
+    T: Copy + Default + Debug,
+{
+    /// Returns the number of elements in the view.
+    ///
+    /// This is a constant-time operation.
+    pub fn len(&self) -> usize {
+        self.end_index - self.start_index
+    }
+
+    /// Returns `true` if the view contains no elements.
+    ///
+    /// This is a constant-time operation.
+    pub fn is_empty(&self) -> bool {
+        self.start_index == self.end_index
+    }
+
+    /// Returns a reference to the value in the view, if any, at the given index.
+    ///
+    /// This is a constant-time operation.
+    pub fn get(&self, index: usize) -> Option<&'a T> {
+        if index >= self.len() {
+            return None;
+        }
+        self.container.get(self.start_index + index)
+    }
+
+    /// Gets an iterator that visits the values in the view in order.
+    pub fn iter(&self) -> Iter<'a, T> {
+        Iter {
+            container: self.container,
+            next_index: self.start_index,
+            end_index: self.end_index,
+        }
+    }
+}
+
+impl<'a, T> IntoIterator for RotatedSlice<'a, T>
+where
+    T: Copy + Default + Debug,
+{
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// A read cursor over a `RotatedVec` that remembers the subarray of the last
+/// accessed index.
+///
+/// Looking up an index in the same subarray as the previous lookup, or in an
+/// adjacent one, skips computing which subarray holds the index. This speeds
+/// up access patterns such as scanning with occasional lookups.
+///
+/// This `struct` is created by the [`cursor`] method on [`RotatedVec`][`RotatedVec`].
+/// See its documentation for more.
+///
+/// [`RotatedVec`]: struct.RotatedVec.html
+/// [`cursor`]: struct.RotatedVec.html#method.cursor
+#[derive(Debug, Copy, Clone)]
+pub struct Cursor<'a, T: 'a> {
+    container: &'a RotatedVec<T>,
+    // the subarray containing the last accessed index
+    subarray_idx: usize,
+    // the index in the backing array where that subarray starts
+    subarray_start_idx: usize,
+}
+
+impl<'a, T> Cursor<'a, T> {
+    /// Returns a reference to the value in the vector, if any, at the given index.
+    ///
+    /// This is a constant-time operation, which is cheaper when `index` is
+    /// near the index of the previous call.
+    pub fn get(&mut self, index: usize) -> Option<&'a T> {
+        let container = self.container;
+        if index >= container.len() {
+            return None;
+        }
+        let next_subarray_start_idx = self.subarray_start_idx + self.subarray_idx + 1;
+        if index < self.subarray_start_idx {
+            // subarray `i - 1` holds the `i` elements just before subarray `i`
+            if index >= self.subarray_start_idx - self.subarray_idx {
+                self.subarray_start_idx -= self.subarray_idx;
+                self.subarray_idx -= 1;
+            } else {
+                self.seek(index);
+            }
+        } else if index >= next_subarray_start_idx {
+            if index < next_subarray_start_idx + self.subarray_idx + 2 {
+                self.subarray_start_idx = next_subarray_start_idx;
+                self.subarray_idx += 1;
+            } else {
+                self.seek(index);
+            }
+        }
+        let real_idx = container.get_real_index_in_subarray(index, self.subarray_idx, self.subarray_start_idx);
+        Some(&container.data[real_idx])
+    }
+
+    fn seek(&mut self, index: usize) {
+        self.subarray_idx = RotatedVec::<T>::get_subarray_idx_from_array_idx(index);
+        self.subarray_start_idx = RotatedVec::<T>::get_array_idx_from_subarray_idx(self.subarray_idx);
+    }
+}
+
+/// An iterator over a `RotatedVec` in (non-overlapping) chunks, starting at
+/// the end of the vector.
+///
+/// This `struct` is created by the [`rchunks`] method on [`RotatedVec`][`RotatedVec`].
+/// See its documentation for more.
+///
+/// [`RotatedVec`]: struct.RotatedVec.html
+/// [`rchunks`]: struct.RotatedVec.html#method.rchunks
+#[derive(Debug, Copy, Clone)]
+pub struct RChunks<'a, T: 'a> {
+    container: &'a RotatedVec<T>,
+    start_index: usize,
+    end_index: usize,
+    chunk_size: usize,
+}
+
+/// An iterator over a `RotatedVec` in (non-overlapping) mutable chunks,
+/// starting at the beginning of the vector.
+///
+/// Since the elements of a chunk may be scattered across rotated subarrays,
+/// each chunk is a `Vec` of mutable references rather than a `&mut [T]`.
+///
+/// This `struct` is created by the [`chunks_mut`] method on [`RotatedVec`][`RotatedVec`].
+/// See its documentation for more.
+///
+/// [`RotatedVec`]: struct.RotatedVec.html
+/// [`chunks_mut`]: struct.RotatedVec.html#method.chunks_mut
+#[derive(Debug)]
+pub struct ChunksMut<'a, T: 'a> {
+    // `IterMut` never yields an element twice, so no two chunks can alias
+    iter: IterMut<'a, T>,
+    chunk_size: usize,
+}
+
+/// An iterator over a `RotatedVec` in runs of adjacent elements, using a
+/// predicate to separate them.
+///
+/// Since the elements of a run may be scattered across rotated subarrays,
+/// each run is yielded as an owned `Vec`.
+///
+/// This `struct` is created by the [`group_by`] method on [`RotatedVec`][`RotatedVec`].
+/// See its documentation for more.
+///
+/// [`RotatedVec`]: struct.RotatedVec.html
+/// [`group_by`]: struct.RotatedVec.html#method.group_by
+#[derive(Debug, Clone)]
+pub struct GroupBy<'a, T: 'a, F>
+where
+    T: Copy + Default + Debug,
+    F: FnMut(&T, &T) -> bool,
+{
+    container: &'a RotatedVec<T>,
+    same_group: F,
+    // index of the first element of the next run
+    index: usize,
+}
+
+/// An iterator which uses a closure to determine if an element should be removed.
+///
+/// This `struct` is created by the [`extract_if`] method on [`RotatedVec`][`RotatedVec`].
+/// See its documentation for more.
+///
+/// [`RotatedVec`]: struct.RotatedVec.html
+/// [`extract_if`]: struct.RotatedVec.html#method.extract_if
+#[derive(Debug)]
+pub struct ExtractIf<'a, T: 'a, F>
+where
+    T: Copy + Default + Debug,
+    F: FnMut(&mut T) -> bool,
+{
+    container: &'a mut RotatedVec<T>,
+    pred: F,
+    // index of the next element to be examined
+    read_index: usize,
+    // index at which the next retained element will be written
+    write_index: usize,
+}
+
+/// A cursor for inserting consecutive elements at a fixed position in a `RotatedVec`.
+///
+/// Inserted elements are buffered and moved into the vector when the cursor is
+/// dropped.
+///
+/// This `struct` is created by the [`insert_cursor`] method on [`RotatedVec`][`RotatedVec`].
+/// See its documentation for more.
+///
+/// [`RotatedVec`]: struct.RotatedVec.html
+/// [`insert_cursor`]: struct.RotatedVec.html#method.insert_cursor
+#[derive(Debug)]
+pub struct InsertCursor<'a, T: 'a>
+where
+    T: Copy + Default + Debug,
+{
+    container: &'a mut RotatedVec<T>,
+    index: usize,
+    pending: Vec<T>,
+}
+
+impl<'a, T> InsertCursor<'a, T>
+where
+    T: Copy + Default + Debug,
+{
+    /// Inserts an element after all elements previously inserted through this
+    /// cursor.
+    ///
+    /// This is an amortized constant-time operation.
+    pub fn insert_next(&mut self, element: T) {
+        self.pending.push(element);
+    }
+}
+
+impl<'a, T> Drop for InsertCursor<'a, T>
+where
+    T: Copy + Default + Debug,
+{
+    fn drop(&mut self) {
+        let container = &mut *self.container;
+        // inserting each element costs O(√n), while rebuilding costs O(n),
+        // so pick whichever is cheaper
+        if self.pending.len() * container.subarray_count() <= container.len() {
+            for (i, &element) in self.pending.iter().enumerate() {
+                container.insert(self.index + i, element);
+            }
+        } else {
+            container.normalize();
+            container.data.splice(self.index..self.index, self.pending.drain(..));
+            container.start_indexes.clear();
+            container.init();
+        }
+        debug_assert!(container.assert_invariants());
+    }
+}
+
 /// An owning iterator over the items of a `RotatedVec`.
 ///
 /// This `struct` is created by the [`into_iter`] method on [`RotatedVec`][`RotatedVec`]
@@ -128,10 +446,7 @@ pub struct IntoIter<T> {
     next_index: usize,
 }
 
-impl<T> RotatedVec<T>
-where
-    T: Copy + Default + Debug,
-{
+impl<T> RotatedVec<T> {
     /// Makes a new `RotatedVec` without any heap allocations.
     ///
     /// This is a constant-time operation.
@@ -148,6 +463,8 @@ where
         RotatedVec {
             data: Vec::new(),
             start_indexes: Vec::new(),
+            #[cfg(feature = "metrics")]
+            move_count: 0,
         }
     }
 
@@ -186,153 +503,557 @@ where
         RotatedVec {
             data: Vec::with_capacity(capacity),
             start_indexes: Vec::with_capacity(start_indexes_capacity),
+            #[cfg(feature = "metrics")]
+            move_count: 0,
         }
     }
 
-
-    /// Returns a reference to the value in the array, if any, at the given index.
+    /// Constructs a new `RotatedVec<T>` of length `len`, where each element is
+    /// the result of calling `f` with its index.
     ///
-    /// This is a constant-time operation.
+    /// The closure is called for indexes `0` through `len - 1`, in order.
+    ///
+    /// This is an `O(n)` operation.
     ///
     /// # Examples
     ///
     /// ```
     /// use rotated_vec::RotatedVec;
     ///
-    /// let vec: RotatedVec<_> = vec![1, 2, 3].into();
-    /// assert_eq!(vec.get(0), Some(&1));
-    /// assert_eq!(vec.get(3), None);
+    /// let vec = RotatedVec::from_fn(4, |i| i * 2);
+    /// assert_eq!(vec, vec![0, 2, 4, 6].into());
     /// ```
-    pub fn get(&self, index: usize) -> Option<&T> {
-        if index >= self.data.len() {
-            return None;
-        }
-        let real_idx = self.get_real_index(index);
-        Some(&self.data[real_idx])
+    pub fn from_fn<F>(len: usize, f: F) -> RotatedVec<T>
+    where
+        F: FnMut(usize) -> T,
+    {
+        let data: Vec<T> = (0..len).map(f).collect();
+        data.into()
     }
 
-    /// Returns a mutable reference to the value in the array, if any, at the given index.
+    /// Flattens a vector of vectors into a single `RotatedVec<T>`, in order.
     ///
-    /// This is a constant-time operation.
+    /// The elements are moved into one flat backing array, which is
+    /// allocated once.
+    ///
+    /// This is an `O(n)` operation.
     ///
     /// # Examples
     ///
     /// ```
     /// use rotated_vec::RotatedVec;
     ///
-    /// let mut vec: RotatedVec<_> = vec![1, 2, 3].into();
-    /// assert_eq!(vec.get_mut(0), Some(&mut 1));
-    /// assert_eq!(vec.get_mut(3), None);
+    /// let vec = RotatedVec::concat(vec![vec![1, 2], vec![], vec![3]]);
+    /// assert_eq!(vec, vec![1, 2, 3].into());
     /// ```
-    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
-        if index >= self.data.len() {
-            return None;
+    pub fn concat(vecs: Vec<Vec<T>>) -> RotatedVec<T> {
+        let mut data = Vec::with_capacity(vecs.iter().map(Vec::len).sum());
+        for mut vec in vecs {
+            data.append(&mut vec);
         }
-        let real_idx = self.get_real_index(index);
-        Some(&mut self.data[real_idx])
+        data.into()
     }
 
-    /// Swaps two elements in the vector.
-    ///
-    /// This is a constant-time operation.
-    ///
-    /// # Arguments
-    ///
-    /// * a - The index of the first element
-    /// * b - The index of the second element
+    /// Collects an iterator of `Result`s into a `RotatedVec<T>`, stopping at
+    /// the first `Err` and returning it.
     ///
-    /// # Panics
+    /// This mirrors collecting into `Result<Vec<T>, E>`, and is useful when
+    /// building a vector from parsed or validated inputs.
     ///
-    /// Panics if `a` or `b` are out of bounds.
+    /// This is an `O(n)` operation.
     ///
     /// # Examples
     ///
     /// ```
     /// use rotated_vec::RotatedVec;
     ///
-    /// let mut vec: RotatedVec<_> = vec!["a", "b", "c", "d"].into();
-    /// vec.swap(1, 3);
-    /// assert_eq!(vec, vec!["a", "d", "c", "b"].into());
+    /// let vec = RotatedVec::try_from_iter("1 2 3".split(' ').map(str::parse::<i32>));
+    /// assert_eq!(vec, Ok(vec![1, 2, 3].into()));
+    ///
+    /// let vec = RotatedVec::try_from_iter("1 x 3".split(' ').map(str::parse::<i32>));
+    /// assert!(vec.is_err());
     /// ```
-    pub fn swap(&mut self, a: usize, b: usize) {
-        self.data.swap(a, b);
+    pub fn try_from_iter<E, I>(iter: I) -> Result<RotatedVec<T>, E>
+    where
+        I: IntoIterator<Item = Result<T, E>>,
+    {
+        let data: Vec<T> = iter.into_iter().collect::<Result<_, E>>()?;
+        Ok(data.into())
     }
 
-    /// Returns the number of elements the vector can hold without
-    /// reallocating.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use rotated_vec::RotatedVec;
+    /// Creates a `RotatedVec<T>` directly from its internal data array and
+    /// offset array, as returned by [`into_raw_parts`].
     ///
-    /// let vec: RotatedVec<i32> = RotatedVec::with_capacity(10);
-    /// assert_eq!(vec.capacity(), 10);
+    /// This is a constant-time operation.
     ///
-    pub fn capacity(&self) -> usize {
-        self.data.capacity()
-    }
-
-    /// Reserves the minimum capacity for exactly `additional` more elements to
-    /// be inserted in the given `RotatedVec<T>`. After calling `reserve_exact`,
-    /// capacity will be greater than or equal to `self.len() + additional`.
-    /// Does nothing if the capacity is already sufficient.
+    /// # Safety
     ///
-    /// Note that the allocator may give the collection more space than it
-    /// requests. Therefore, capacity can not be relied upon to be precisely
-    /// minimal. Prefer `reserve` if future insertions are expected.
+    /// The caller must ensure that the parts describe a valid layout:
     ///
-    /// # Panics
+    /// * `start_indexes` has one entry per subarray, where subarray `i`
+    ///   holds `i + 1` elements of `data` except that the last subarray may
+    ///   be only partially full. That is, `start_indexes.len()` is the smallest
+    ///   `k` such that `k * (k + 1) / 2 >= data.len()`.
+    /// * Each entry `start_indexes[i]` (the offset of the first element of
+    ///   subarray `i`) is at most `i`.
+    /// * If the last subarray is only partially full, its entry is 0.
     ///
-    /// Panics if the new capacity overflows `usize`.
+    /// Violating these invariants may cause distinct logical indexes to map to
+    /// the same element, which breaks the aliasing guarantees of `iter_mut`.
+    /// Use [`from_raw_parts_checked`] to validate the parts instead.
     ///
     /// # Examples
     ///
     /// ```
     /// use rotated_vec::RotatedVec;
     ///
-    /// let mut vec: RotatedVec<_> = vec![1].into();
-    /// vec.reserve_exact(10);
-    /// assert!(vec.capacity() >= 11);
+    /// let mut vec: RotatedVec<_> = vec![1, 2, 3, 4].into();
+    /// vec.insert(0, 0);
+    /// let (data, start_indexes) = vec.clone().into_raw_parts();
+    /// let rebuilt = unsafe { RotatedVec::from_raw_parts(data, start_indexes) };
+    /// assert_eq!(rebuilt, vec);
     /// ```
-    pub fn reserve_exact(&mut self, additional: usize) {
-        self.data.reserve_exact(additional);
+    ///
+    /// [`into_raw_parts`]: #method.into_raw_parts
+    /// [`from_raw_parts_checked`]: #method.from_raw_parts_checked
+    pub unsafe fn from_raw_parts(data: Vec<T>, start_indexes: Vec<usize>) -> RotatedVec<T> {
+        let this = RotatedVec {
+            data,
+            start_indexes,
+            #[cfg(feature = "metrics")]
+            move_count: 0,
+        };
+        debug_assert!(this.is_valid_layout());
+        this
     }
 
-    /// Reserves capacity for at least `additional` more elements to be inserted
-    /// in the given `RotatedVec<T>`. The collection may reserve more space to avoid
-    /// frequent reallocations. After calling `reserve`, capacity will be
-    /// greater than or equal to `self.len() + additional`. Does nothing if
-    /// capacity is already sufficient.
+    /// Creates a `RotatedVec<T>` from its internal data array and offset
+    /// array, as returned by [`into_raw_parts`], or returns the parts back if
+    /// they don't describe a valid layout.
     ///
-    /// # Panics
+    /// See [`from_raw_parts`] for the invariants that are checked.
     ///
-    /// Panics if the new capacity overflows `usize`.
+    /// This is an `O(√n)` operation.
     ///
     /// # Examples
     ///
     /// ```
     /// use rotated_vec::RotatedVec;
     ///
-    /// let mut vec: RotatedVec<_> = vec![1].into();
-    /// vec.reserve(10);
-    /// assert!(vec.capacity() >= 11);
+    /// assert!(RotatedVec::from_raw_parts_checked(vec![1, 2, 3], vec![0, 1]).is_ok());
+    /// assert!(RotatedVec::from_raw_parts_checked(vec![1, 2, 3], vec![0, 2]).is_err());
+    /// assert!(RotatedVec::from_raw_parts_checked(vec![1, 2, 3], vec![0]).is_err());
     /// ```
-    pub fn reserve(&mut self, additional: usize) {
-        self.data.reserve(additional);
+    ///
+    /// [`into_raw_parts`]: #method.into_raw_parts
+    /// [`from_raw_parts`]: #method.from_raw_parts
+    pub fn from_raw_parts_checked(
+        data: Vec<T>,
+        start_indexes: Vec<usize>,
+    ) -> Result<RotatedVec<T>, (Vec<T>, Vec<usize>)> {
+        let this = RotatedVec {
+            data,
+            start_indexes,
+            #[cfg(feature = "metrics")]
+            move_count: 0,
+        };
+        if this.is_valid_layout() {
+            Ok(this)
+        } else {
+            Err((this.data, this.start_indexes))
+        }
     }
 
-    /// Shrinks the capacity of the vector as much as possible.
+    /// Decomposes a `RotatedVec<T>` into its internal data array and offset
+    /// array, without un-rotating the data.
     ///
-    /// It will drop down as close as possible to the length but the allocator
-    /// may still inform the vector that there is space for a few more elements.
+    /// The parts can be reassembled with [`from_raw_parts`] or
+    /// [`from_raw_parts_checked`].
+    ///
+    /// This is a constant-time operation.
     ///
     /// # Examples
     ///
     /// ```
     /// use rotated_vec::RotatedVec;
     ///
-    /// let mut vec = RotatedVec::with_capacity(10);
+    /// let vec: RotatedVec<_> = vec![1, 2, 3].into();
+    /// let (data, start_indexes) = vec.into_raw_parts();
+    /// assert_eq!(data, vec![1, 2, 3]);
+    /// assert_eq!(start_indexes, vec![0, 0]);
+    /// ```
+    ///
+    /// [`from_raw_parts`]: #method.from_raw_parts
+    /// [`from_raw_parts_checked`]: #method.from_raw_parts_checked
+    pub fn into_raw_parts(self) -> (Vec<T>, Vec<usize>) {
+        (self.data, self.start_indexes)
+    }
+
+    /// Returns a reference to the value in the array, if any, at the given index.
+    ///
+    /// This is a constant-time operation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::RotatedVec;
+    ///
+    /// let vec: RotatedVec<_> = vec![1, 2, 3].into();
+    /// assert_eq!(vec.get(0), Some(&1));
+    /// assert_eq!(vec.get(3), None);
+    /// ```
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index >= self.data.len() {
+            return None;
+        }
+        let real_idx = self.get_real_index(index);
+        Some(&self.data[real_idx])
+    }
+
+    /// Returns a mutable reference to the value in the array, if any, at the given index.
+    ///
+    /// This is a constant-time operation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::RotatedVec;
+    ///
+    /// let mut vec: RotatedVec<_> = vec![1, 2, 3].into();
+    /// assert_eq!(vec.get_mut(0), Some(&mut 1));
+    /// assert_eq!(vec.get_mut(3), None);
+    /// ```
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index >= self.data.len() {
+            return None;
+        }
+        let real_idx = self.get_real_index(index);
+        Some(&mut self.data[real_idx])
+    }
+
+    /// Returns a read cursor over the vector, which remembers the subarray of
+    /// the last accessed index.
+    ///
+    /// Looking up an index near the previous one through the cursor avoids
+    /// recomputing which subarray holds it, which speeds up access patterns
+    /// such as scanning with occasional lookups.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::RotatedVec;
+    ///
+    /// let vec: RotatedVec<_> = (0..100).collect();
+    /// let mut cursor = vec.cursor();
+    /// assert_eq!(cursor.get(50), Some(&50));
+    /// assert_eq!(cursor.get(51), Some(&51));
+    /// assert_eq!(cursor.get(100), None);
+    /// ```
+    pub fn cursor(&self) -> Cursor<'_, T> {
+        Cursor {
+            container: self,
+            subarray_idx: 0,
+            subarray_start_idx: 0,
+        }
+    }
+
+    /// Returns a raw pointer to the backing storage.
+    ///
+    /// The pointed-to array holds `len()` initialized elements in *physical*
+    /// order, which is generally not the logical order seen through
+    /// [`get`] or [`iter`]. The backing array is split into subarrays of
+    /// lengths 1, 2, 3, ..., each of which may be rotated by an internal
+    /// start index that is not exposed. So the element at physical offset
+    /// `i` cannot be interpreted without that internal layout: use
+    /// [`real_indices`] to map logical indexes to physical offsets, or call
+    /// [`normalize`] first if you need the physical order to match the
+    /// logical order.
+    ///
+    /// The pointer is invalidated by any operation that may reallocate or
+    /// rearrange the vector, and the caller must not write through it.
+    ///
+    /// This is a constant-time operation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::RotatedVec;
+    ///
+    /// let mut vec: RotatedVec<_> = vec![1, 2, 3, 4].into();
+    /// vec.insert(0, 0);
+    /// vec.normalize();
+    /// let ptr = vec.as_backing_ptr();
+    /// for i in 0..vec.len() {
+    ///     assert_eq!(unsafe { *ptr.add(i) }, vec[i]);
+    /// }
+    /// ```
+    ///
+    /// [`get`]: #method.get
+    /// [`iter`]: #method.iter
+    /// [`real_indices`]: #method.real_indices
+    /// [`normalize`]: #method.normalize
+    pub fn as_backing_ptr(&self) -> *const T {
+        self.data.as_ptr()
+    }
+
+    /// Returns an unsafe mutable pointer to the backing storage.
+    ///
+    /// The same layout caveats as for [`as_backing_ptr`] apply: elements are
+    /// in physical rotated order, so writes through this pointer must take
+    /// the internal layout into account (or follow a call to [`normalize`]).
+    /// The pointer is invalidated by any operation that may reallocate or
+    /// rearrange the vector.
+    ///
+    /// This is a constant-time operation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::RotatedVec;
+    ///
+    /// let mut vec: RotatedVec<_> = vec![1, 2, 3].into();
+    /// vec.normalize();
+    /// let ptr = vec.as_backing_mut_ptr();
+    /// for i in 0..vec.len() {
+    ///     unsafe { *ptr.add(i) *= 10; }
+    /// }
+    /// assert_eq!(vec, RotatedVec::from(vec![10, 20, 30]));
+    /// ```
+    ///
+    /// [`as_backing_ptr`]: #method.as_backing_ptr
+    /// [`normalize`]: #method.normalize
+    pub fn as_backing_mut_ptr(&mut self) -> *mut T {
+        self.data.as_mut_ptr()
+    }
+
+    /// Returns an iterator over the physical index in the backing storage of
+    /// each element, in logical order.
+    ///
+    /// This is a low-level primitive for building custom gather or scatter
+    /// operations over the storage exposed by [`as_backing_ptr`] or
+    /// [`into_raw_parts`]: the `i`th item yielded is the offset of the element
+    /// at logical index `i`. The indexes are only valid until the vector is
+    /// next modified.
+    ///
+    /// Consecutive indexes are computed incrementally within each subarray,
+    /// so iterating over all of them is an `O(n)` operation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::RotatedVec;
+    ///
+    /// let mut vec: RotatedVec<_> = vec![1, 2, 3].into();
+    /// vec.insert(0, 0);
+    /// let real_indices: Vec<usize> = vec.real_indices().collect();
+    /// let (data, _) = vec.clone().into_raw_parts();
+    /// for (i, &real_idx) in real_indices.iter().enumerate() {
+    ///     assert_eq!(data[real_idx], vec[i]);
+    /// }
+    /// ```
+    ///
+    /// [`as_backing_ptr`]: #method.as_backing_ptr
+    /// [`into_raw_parts`]: #method.into_raw_parts
+    pub fn real_indices(&self) -> impl Iterator<Item = usize> + '_ {
+        self.real_ranges(0, self.len()).flatten()
+    }
+
+    /// Returns the number of subarrays in the internal layout.
+    ///
+    /// The vector stores its elements in a sequence of subarrays of increasing
+    /// length, so the number of subarrays grows as `O(√n)`.
+    ///
+    /// This is a constant-time operation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::RotatedVec;
+    ///
+    /// let vec: RotatedVec<_> = vec![1, 2, 3, 4, 5].into();
+    /// assert_eq!(vec.subarray_count(), 3);
+    /// ```
+    pub fn subarray_count(&self) -> usize {
+        self.start_indexes.len()
+    }
+
+    /// Returns the number of elements in the given subarray of the internal
+    /// layout, or `None` if there is no such subarray.
+    ///
+    /// Subarray `i` holds `i + 1` elements, except that the last subarray may
+    /// be only partially full.
+    ///
+    /// This is a constant-time operation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::RotatedVec;
+    ///
+    /// let vec: RotatedVec<_> = vec![1, 2, 3, 4, 5].into();
+    /// assert_eq!(vec.subarray_len(1), Some(2));
+    /// assert_eq!(vec.subarray_len(2), Some(2));
+    /// assert_eq!(vec.subarray_len(3), None);
+    /// ```
+    pub fn subarray_len(&self, subarray_idx: usize) -> Option<usize> {
+        if subarray_idx >= self.start_indexes.len() {
+            return None;
+        }
+        let subarray_start_idx = Self::get_array_idx_from_subarray_idx(subarray_idx);
+        Some(min(subarray_idx + 1, self.data.len() - subarray_start_idx))
+    }
+
+    /// Returns the number of elements the vector can hold without
+    /// reallocating.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::RotatedVec;
+    ///
+    /// let vec: RotatedVec<i32> = RotatedVec::with_capacity(10);
+    /// assert_eq!(vec.capacity(), 10);
+    ///
+    pub fn capacity(&self) -> usize {
+        self.data.capacity()
+    }
+
+    /// Returns the number of bytes allocated by the vector, including the
+    /// internal offset array as well as the element storage.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::mem::size_of;
+    /// use rotated_vec::RotatedVec;
+    ///
+    /// let vec: RotatedVec<u32> = RotatedVec::with_capacity(10);
+    /// assert_eq!(vec.memory_usage(), 10 * size_of::<u32>() + 4 * size_of::<usize>());
+    /// ```
+    pub fn memory_usage(&self) -> usize {
+        self.data.capacity() * mem::size_of::<T>()
+            + self.start_indexes.capacity() * mem::size_of::<usize>()
+    }
+
+    /// Reserves the minimum capacity for exactly `additional` more elements to
+    /// be inserted in the given `RotatedVec<T>`. After calling `reserve_exact`,
+    /// capacity will be greater than or equal to `self.len() + additional`.
+    /// Does nothing if the capacity is already sufficient.
+    ///
+    /// Note that the allocator may give the collection more space than it
+    /// requests. Therefore, capacity can not be relied upon to be precisely
+    /// minimal. Prefer `reserve` if future insertions are expected.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity overflows `usize`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::RotatedVec;
+    ///
+    /// let mut vec: RotatedVec<_> = vec![1].into();
+    /// vec.reserve_exact(10);
+    /// assert!(vec.capacity() >= 11);
+    /// ```
+    pub fn reserve_exact(&mut self, additional: usize) {
+        self.data.reserve_exact(additional);
+    }
+
+    /// Reserves capacity for at least `additional` more elements to be inserted
+    /// in the given `RotatedVec<T>`. The collection may reserve more space to avoid
+    /// frequent reallocations. After calling `reserve`, capacity will be
+    /// greater than or equal to `self.len() + additional`. Does nothing if
+    /// capacity is already sufficient.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity overflows `usize`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::RotatedVec;
+    ///
+    /// let mut vec: RotatedVec<_> = vec![1].into();
+    /// vec.reserve(10);
+    /// assert!(vec.capacity() >= 11);
+    /// ```
+    pub fn reserve(&mut self, additional: usize) {
+        self.data.reserve(additional);
+    }
+
+    /// Tries to reserve capacity for at least `additional` more elements to be
+    /// inserted in the given `RotatedVec<T>`. The collection may reserve more
+    /// space to avoid frequent reallocations. After calling `try_reserve`,
+    /// capacity will be greater than or equal to `self.len() + additional` if
+    /// it returns `Ok(())`. Does nothing if capacity is already sufficient.
+    ///
+    /// # Errors
+    ///
+    /// If the capacity overflows, or the allocator reports a failure, then an
+    /// error is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::RotatedVec;
+    ///
+    /// let mut vec: RotatedVec<u32> = vec![1].into();
+    /// assert!(vec.try_reserve(10).is_ok());
+    /// assert!(vec.capacity() >= 11);
+    /// assert!(vec.try_reserve(usize::MAX).is_err());
+    /// ```
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.data.try_reserve(additional)?;
+        let additional_subarrays = self.additional_subarrays(additional);
+        self.start_indexes.try_reserve(additional_subarrays)
+    }
+
+    /// Tries to reserve the minimum capacity for exactly `additional` more
+    /// elements to be inserted in the given `RotatedVec<T>`. After calling
+    /// `try_reserve_exact`, capacity will be greater than or equal to
+    /// `self.len() + additional` if it returns `Ok(())`. Does nothing if the
+    /// capacity is already sufficient.
+    ///
+    /// Note that the allocator may give the collection more space than it
+    /// requests. Therefore, capacity can not be relied upon to be precisely
+    /// minimal. Prefer `try_reserve` if future insertions are expected.
+    ///
+    /// # Errors
+    ///
+    /// If the capacity overflows, or the allocator reports a failure, then an
+    /// error is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::RotatedVec;
+    ///
+    /// let mut vec: RotatedVec<u32> = vec![1].into();
+    /// assert!(vec.try_reserve_exact(10).is_ok());
+    /// assert!(vec.capacity() >= 11);
+    /// assert!(vec.try_reserve_exact(usize::MAX).is_err());
+    /// ```
+    pub fn try_reserve_exact(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.data.try_reserve_exact(additional)?;
+        let additional_subarrays = self.additional_subarrays(additional);
+        self.start_indexes.try_reserve_exact(additional_subarrays)
+    }
+
+    /// Shrinks the capacity of the vector as much as possible.
+    ///
+    /// It will drop down as close as possible to the length but the allocator
+    /// may still inform the vector that there is space for a few more elements.
+    /// The internal offset array is shrunk to the number of subarrays as well.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::RotatedVec;
+    ///
+    /// let mut vec = RotatedVec::with_capacity(10);
     /// vec.extend([1, 2, 3].iter().cloned());
     /// assert_eq!(vec.capacity(), 10);
     /// vec.shrink_to_fit();
@@ -340,6 +1061,7 @@ where
     /// ```
     pub fn shrink_to_fit(&mut self) {
         self.data.shrink_to_fit();
+        self.start_indexes.shrink_to_fit();
     }
 
     /// Shortens the vector, keeping the first `len` elements and dropping
@@ -360,180 +1082,2097 @@ where
     /// ```
     /// use rotated_vec::RotatedVec;
     ///
-    /// let mut vec: RotatedVec<_> = vec![1, 2, 3, 4, 5].into();
-    /// vec.truncate(2);
-    /// assert_eq!(vec, vec![1, 2].into());
-    /// ```
+    /// let mut vec: RotatedVec<_> = vec![1, 2, 3, 4, 5].into();
+    /// vec.truncate(2);
+    /// assert_eq!(vec, vec![1, 2].into());
+    /// ```
+    ///
+    /// No truncation occurs when `len` is greater than the vector's current
+    /// length:
+    ///
+    /// ```
+    /// use rotated_vec::RotatedVec;
+    ///
+    /// let mut vec: RotatedVec<_> = vec![1, 2, 3].into();
+    /// vec.truncate(8);
+    /// assert_eq!(vec, vec![1, 2, 3].into());
+    /// ```
+    ///
+    /// Truncating when `len == 0` is equivalent to calling the [`clear`]
+    /// method.
+    ///
+    /// ```
+    /// use rotated_vec::RotatedVec;
+    ///
+    /// let mut vec: RotatedVec<_> = vec![1, 2, 3].into();
+    /// vec.truncate(0);
+    /// assert_eq!(vec, vec![].into());
+    /// ```
+    ///
+    /// [`clear`]: #method.clear
+    pub fn truncate(&mut self, len: usize) {
+        if len >= self.len() {
+            return;
+        }
+        if len == 0 {
+            self.clear();
+            return;
+        }
+        // conceptually, we drop all subarrays after the truncated length,
+        // then un-rotate the new last subarray, then drop any remaining elements.
+        let last_subarray_idx = Self::get_subarray_idx_from_array_idx(len - 1);
+        self.start_indexes.truncate(last_subarray_idx + 1);
+        let last_subarray_start_idx = Self::get_array_idx_from_subarray_idx(last_subarray_idx);
+        let last_subarray_end_idx = min(last_subarray_start_idx + last_subarray_idx + 1, self.data.len());
+        let pivot_offset = self.start_indexes[last_subarray_idx];
+        self.data[last_subarray_start_idx..last_subarray_end_idx].rotate_left(pivot_offset);
+        self.start_indexes[last_subarray_idx] = 0;
+        // now every element past `len` is in a dropped position, so each is dropped exactly once
+        self.data.truncate(len);
+        debug_assert!(self.assert_invariants());
+    }
+
+    /// Shortens the vector, keeping the first `len` elements and dropping
+    /// the rest, then shrinks its capacity as much as possible.
+    ///
+    /// This is equivalent to calling [`truncate`] followed by
+    /// [`shrink_to_fit`]. It is useful for long-lived vectors that shrink
+    /// drastically, since [`truncate`] keeps the capacity of both the element
+    /// storage and the internal offset array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::RotatedVec;
+    ///
+    /// let mut vec: RotatedVec<_> = (0..1000).collect();
+    /// vec.truncate_and_shrink(2);
+    /// assert_eq!(vec, vec![0, 1].into());
+    /// assert!(vec.capacity() < 1000);
+    /// ```
+    ///
+    /// [`truncate`]: #method.truncate
+    /// [`shrink_to_fit`]: #method.shrink_to_fit
+    pub fn truncate_and_shrink(&mut self, len: usize) {
+        self.truncate(len);
+        self.shrink_to_fit();
+    }
+
+    /// Removes elements from the back of the vector as long as `pred` returns
+    /// `true` for them, stopping at the first element for which it returns
+    /// `false`.
+    ///
+    /// This is analogous to trimming trailing whitespace from a string.
+    /// `pred` is called on elements from the back, in reverse order.
+    ///
+    /// This is an `O(k + √n)` operation, where `k` is the number of elements
+    /// examined.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::RotatedVec;
+    ///
+    /// let mut vec: RotatedVec<_> = vec![1, 0, 2, 0, 0].into();
+    /// vec.truncate_while(|&x| x == 0);
+    /// assert_eq!(vec, vec![1, 0, 2].into());
+    /// ```
+    pub fn truncate_while<F>(&mut self, mut pred: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let mut len = self.len();
+        while len > 0 && pred(self.get(len - 1).unwrap()) {
+            len -= 1;
+        }
+        self.truncate(len);
+    }
+
+    /// Returns the number of elements in the set.
+    ///
+    /// This is a constant-time operation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::RotatedVec;
+    ///
+    /// let mut vec = RotatedVec::new();
+    /// assert_eq!(vec.len(), 0);
+    /// vec.push(1);
+    /// assert_eq!(vec.len(), 1);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns `true` if the set contains no elements.
+    ///
+    /// This is a constant-time operation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::RotatedVec;
+    ///
+    /// let mut vec = RotatedVec::new();
+    /// assert!(vec.is_empty());
+    /// vec.push(1);
+    /// assert!(!vec.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Clears the vector, removing all values.
+    ///
+    /// This is a constant-time operation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::RotatedVec;
+    ///
+    /// let mut vec = RotatedVec::new();
+    /// vec.push(1);
+    /// vec.clear();
+    /// assert!(vec.is_empty());
+    /// ```
+    pub fn clear(&mut self) {
+        self.data.clear();
+        self.start_indexes.clear();
+    }
+
+    /// Clears the vector, removing all values and releasing all allocated
+    /// memory.
+    ///
+    /// This is a constant-time operation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::RotatedVec;
+    ///
+    /// let mut vec: RotatedVec<_> = vec![1, 2, 3].into();
+    /// vec.clear_and_shrink();
+    /// assert!(vec.is_empty());
+    /// assert_eq!(vec.capacity(), 0);
+    /// ```
+    pub fn clear_and_shrink(&mut self) {
+        self.data = Vec::new();
+        self.start_indexes = Vec::new();
+    }
+
+    /// Appends an element to the back of a collection.
+    ///
+    /// This is a constant-time operation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of elements in the vector overflows a `usize`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::RotatedVec;
+    ///
+    /// let mut vec: RotatedVec<_> = vec![1, 2].into();
+    /// vec.push(3);
+    /// assert_eq!(vec, vec![1, 2, 3].into());
+    /// ```
+    pub fn push(&mut self, value: T) {
+        // the last subarray is never rotated unless it is full, so we can
+        // always append directly, starting a new subarray if necessary
+        if self.is_last_subarray_full() {
+            self.start_indexes.push(0);
+        }
+        self.record_moves(1);
+        self.data.push(value);
+        debug_assert!(self.assert_invariants());
+    }
+
+    /// Appends `additional` copies of `value` to the back of the vector.
+    ///
+    /// Capacity for all the new elements is reserved up front, so this
+    /// reallocates at most once.
+    ///
+    /// This is an `O(k)` operation, where `k` is `additional`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of elements in the vector overflows a `usize`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::RotatedVec;
+    ///
+    /// let mut vec: RotatedVec<_> = vec![1, 2].into();
+    /// vec.grow_by(3, 0);
+    /// assert_eq!(vec, vec![1, 2, 0, 0, 0].into());
+    /// ```
+    pub fn grow_by(&mut self, additional: usize, value: T)
+    where
+        T: Clone,
+    {
+        if additional == 0 {
+            return;
+        }
+        self.reserve(additional);
+        // as with `push`, the last subarray is never rotated unless it is full,
+        // so we can append directly and leave every existing pivot intact
+        let new_len = self.data.len().checked_add(additional).expect("capacity overflow");
+        self.record_moves(additional);
+        self.data.resize(new_len, value);
+        let last_subarray_idx = Self::get_subarray_idx_from_array_idx(new_len - 1);
+        self.start_indexes.resize(last_subarray_idx + 1, 0);
+        debug_assert!(self.assert_invariants());
+    }
+
+    /// Removes the last element from a vector and returns it, or [`None`] if it
+    /// is empty.
+    ///
+    /// This is a constant-time operation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::RotatedVec;
+    ///
+    /// let mut vec: RotatedVec<_> = vec![1, 2, 3].into();
+    /// assert_eq!(vec.pop(), Some(3));
+    /// assert_eq!(vec, vec![1, 2].into());
+    /// ```
+    pub fn pop(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+        let max_subarray_idx = self.start_indexes.len() - 1;
+        let max_subarray_offset = Self::get_array_idx_from_subarray_idx(max_subarray_idx);
+        // if the last subarray was rotated, un-rotate it to maintain insert invariant
+        if self.start_indexes[max_subarray_idx] != 0 {
+            self.record_moves(self.data.len() - max_subarray_offset);
+            self.unrotate_last_subarray();
+        }
+        let element = self.data.pop();
+        // if last subarray is now empty, trim start_indexes
+        if max_subarray_offset == self.data.len() {
+            self.start_indexes.pop();
+        }
+        debug_assert!(self.assert_invariants());
+        element
+    }
+
+    /// Removes and returns the element at position `index` within the vector.
+    ///
+    /// This is an `O(√n)` operation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::RotatedVec;
+    ///
+    /// let mut vec: RotatedVec<_> = vec![1, 2, 3].into();
+    /// assert_eq!(vec.remove(1), 2);
+    /// assert_eq!(vec, vec![1, 3].into());
+    /// ```
+    pub fn remove(&mut self, index: usize) -> T {
+        assert!(index < self.len());
+        let old_len = self.len();
+        let mut remove_idx = self.get_real_index(index);
+        let max_subarray_idx = self.start_indexes.len() - 1;
+        let max_subarray_offset = Self::get_array_idx_from_subarray_idx(max_subarray_idx);
+        // find subarray containing the element to remove
+        let subarray_idx = Self::get_subarray_idx_from_array_idx(remove_idx);
+        debug_assert!(subarray_idx <= max_subarray_idx);
+        let subarray_offset = Self::get_array_idx_from_subarray_idx(subarray_idx);
+        // if the last subarray was rotated, un-rotate it to maintain insert invariant
+        if self.is_last_subarray_full() {
+            let last_start_offset = self.start_indexes[max_subarray_idx];
+            // rotate left by the start offset
+            self.data[max_subarray_offset..].rotate_left(last_start_offset);
+            if last_start_offset != 0 {
+                self.record_moves(self.data.len() - max_subarray_offset);
+            }
+            self.start_indexes[max_subarray_idx] = 0;
+            // the remove index might change after un-rotating the last subarray
+            if subarray_idx == max_subarray_idx {
+                remove_idx = self.get_real_index(index);
+            }
+        }
+        // fast path: if the element is in the last subarray, no other subarray is affected,
+        // so we can remove it directly from the (now un-rotated) last subarray.
+        if subarray_idx == max_subarray_idx {
+            self.record_moves(self.data.len() - remove_idx - 1);
+            let element = self.data.remove(remove_idx);
+            // if last subarray is now empty, trim start_indexes
+            if max_subarray_offset == self.data.len() {
+                self.start_indexes.pop();
+            }
+            debug_assert!(self.len() == old_len - 1);
+            debug_assert!(self.assert_invariants());
+            return element;
+        }
+        // otherwise, perform a "hard exchange" in the subarray containing the element.
+        // From now on, we can assume that the subarray we're removing from is full.
+        let next_subarray_offset = Self::get_array_idx_from_subarray_idx(subarray_idx + 1);
+        let subarray = &mut self.data[subarray_offset..next_subarray_offset];
+        let pivot_offset = self.start_indexes[subarray_idx];
+        let remove_offset = remove_idx - subarray_offset;
+        let end_offset = if pivot_offset == 0 {
+            subarray.len() - 1
+        } else {
+            pivot_offset - 1
+        };
+        // this logic is best understood with a diagram of a rotated array, e.g.:
+        //
+        // ------------------------------------------------------------------------
+        // | 12 | 13 | 14 | 15 | 16 | 1 | 2 | 3 | 4 | 5 | 6 | 7 | 8 | 9 | 10 | 11 |
+        // ------------------------------------------------------------------------
+        //
+        // rather than overwriting the element to remove, we rotate it into the slot
+        // that the next subarray's first element will fill, so it can be carried
+        // along to the last subarray and removed from the backing array there.
+        let (mut prev_end_offset, subarray_move_count) = if end_offset < pivot_offset && remove_offset >= pivot_offset
+        {
+            subarray[pivot_offset..=remove_offset].rotate_right(1);
+            let new_pivot_offset = if pivot_offset == subarray.len() - 1 {
+                0
+            } else {
+                pivot_offset + 1
+            };
+            self.start_indexes[subarray_idx] = new_pivot_offset;
+            (pivot_offset, remove_offset - pivot_offset)
+        } else {
+            subarray[remove_offset..=end_offset].rotate_left(1);
+            (end_offset, end_offset - remove_offset)
+        };
+        self.record_moves(subarray_move_count);
+        let next_subarray_idx = min(max_subarray_idx, subarray_idx + 1);
+        // now perform an "easy exchange" in all remaining subarrays except the last,
+        // swapping the last element of each with the first element of its successor.
+        for (i, pivot_offset_ref) in self.start_indexes[next_subarray_idx..max_subarray_idx]
+            .iter_mut()
+            .enumerate()
+        {
+            let cur_subarray_idx = next_subarray_idx + i;
+            let cur_subarray_offset = Self::get_array_idx_from_subarray_idx(cur_subarray_idx);
+            let prev_end_idx =
+                prev_end_offset + Self::get_array_idx_from_subarray_idx(cur_subarray_idx - 1);
+            self.data.swap(prev_end_idx, cur_subarray_offset + *pivot_offset_ref);
+            prev_end_offset = *pivot_offset_ref;
+            let new_start_offset = if *pivot_offset_ref == cur_subarray_idx {
+                0
+            } else {
+                *pivot_offset_ref + 1
+            };
+            *pivot_offset_ref = new_start_offset;
+        }
+        // now we fix up the last subarray. if it was initially full, we need to un-rotate it to maintain the insert invariant.
+        // if the removed element is in the last subarray, we just un-rotate and remove() on the vec, updating auxiliary arrays.
+        // otherwise, we swap the first element into the last position of the previous subarray, then remove it and fix up
+        // auxiliary arrays.
+        let prev_end_idx =
+            prev_end_offset + Self::get_array_idx_from_subarray_idx(max_subarray_idx - 1);
+        // since the last subarray is always in order, its first element is always on the first offset
+        self.data.swap(prev_end_idx, max_subarray_offset);
+        // one move per easy exchange, plus one for the last subarray
+        self.record_moves(max_subarray_idx - subarray_idx);
+        // we end up deleting the first element of the last subarray, which is
+        // always at the first offset since it's in order
+        self.record_moves(self.data.len() - max_subarray_offset - 1);
+        let element = self.data.remove(max_subarray_offset);
+        // if last subarray is now empty, trim start_indexes
+        if max_subarray_offset == self.data.len() {
+            self.start_indexes.pop();
+        }
+        debug_assert!(self.len() == old_len - 1);
+        debug_assert!(self.assert_invariants());
+        element
+    }
+
+    /// Un-rotates every subarray in place, so that the backing storage is in
+    /// logical order.
+    ///
+    /// This does not change the length or the subarray layout, but afterward
+    /// [`as_logical_slice`] is guaranteed to return `Some`.
+    ///
+    /// This is an `O(n)` operation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::RotatedVec;
+    ///
+    /// let mut vec: RotatedVec<_> = vec![1, 2, 3, 4].into();
+    /// vec.insert(0, 0);
+    /// assert_eq!(vec.as_logical_slice(), None);
+    /// vec.normalize();
+    /// assert_eq!(vec.as_logical_slice(), Some(&[0, 1, 2, 3, 4][..]));
+    /// ```
+    ///
+    /// [`as_logical_slice`]: #method.as_logical_slice
+    pub fn normalize(&mut self) {
+        self.unrotate_subarrays_from(0);
+    }
+
+    /// Moves all the elements of the vector onto the end of `sink`, in order,
+    /// leaving the vector empty.
+    ///
+    /// Unlike [`append`], the destination is a plain `Vec`. The vector keeps
+    /// its allocated capacity, so it can be refilled without reallocating.
+    ///
+    /// This is an `O(n)` operation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::RotatedVec;
+    ///
+    /// let mut vec: RotatedVec<_> = vec![1, 2, 3].into();
+    /// vec.insert(0, 0);
+    /// let mut sink = vec![-1];
+    /// vec.drain_into(&mut sink);
+    /// assert_eq!(sink, vec![-1, 0, 1, 2, 3]);
+    /// assert!(vec.is_empty());
+    /// ```
+    ///
+    /// [`append`]: #method.append
+    pub fn drain_into(&mut self, sink: &mut Vec<T>) {
+        self.normalize();
+        sink.append(&mut self.data);
+        self.start_indexes.clear();
+    }
+
+    /// Splits the vector into two at the given index.
+    ///
+    /// Returns a newly allocated vector containing the elements in the range
+    /// `[at, len)`. After the call, the original vector will be left containing
+    /// the elements `[0, at)`.
+    ///
+    /// This is an `O(n)` operation. If `at` falls exactly on the start of a
+    /// subarray of the internal layout, the elements before it are left in
+    /// place and only the `m` split-off elements are moved, in `O(m)` time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at > len`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::RotatedVec;
+    ///
+    /// let mut vec: RotatedVec<_> = vec![1, 2, 3].into();
+    /// let vec2 = vec.split_off(1);
+    /// assert_eq!(vec, vec![1].into());
+    /// assert_eq!(vec2, vec![2, 3].into());
+    /// ```
+    pub fn split_off(&mut self, at: usize) -> Self {
+        assert!(at <= self.len(), "`at` out of bounds");
+        if at == self.len() {
+            return RotatedVec::new();
+        }
+        let at_subarray_idx = Self::get_subarray_idx_from_array_idx(at);
+        let other_data = if Self::get_array_idx_from_subarray_idx(at_subarray_idx) == at {
+            // `at` starts a subarray, so every subarray before it stays intact
+            // and only the split-off subarrays need to be un-rotated
+            self.record_moves(self.len() - at);
+            self.unrotate_subarrays_from(at_subarray_idx);
+            self.start_indexes.truncate(at_subarray_idx);
+            self.data.split_off(at)
+        } else {
+            self.record_moves(self.len());
+            // un-rotate so the data array is in logical order, then split it directly
+            self.normalize();
+            let other_data = self.data.split_off(at);
+            self.start_indexes.clear();
+            self.init();
+            other_data
+        };
+        debug_assert!(self.assert_invariants());
+        RotatedVec::from(other_data)
+    }
+
+    /// Retains only the elements specified by the predicate.
+    ///
+    /// In other words, remove all elements `e` such that `f(&e)` returns `false`.
+    /// This method operates in place, visiting each element exactly once in the
+    /// original order, and preserves the order of the retained elements.
+    ///
+    /// If no element is removed, the internal layout is left untouched.
+    ///
+    /// This is an `O(n)` operation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::RotatedVec;
+    ///
+    /// let mut vec: RotatedVec<_> = vec![1, 2, 3, 4].into();
+    /// vec.retain(|&x| x % 2 == 0);
+    /// assert_eq!(vec, vec![2, 4].into());
+    /// ```
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        // find the first element to remove without disturbing the layout
+        let first_removed = match (0..self.len()).position(|i| !f(&self.data[self.get_real_index(i)])) {
+            Some(index) => index,
+            None => return,
+        };
+        // operate directly on the data array in logical order, skipping the
+        // elements that have already been visited
+        self.normalize();
+        let mut index = 0;
+        self.data.retain(|x| {
+            let keep = index < first_removed || (index > first_removed && f(x));
+            index += 1;
+            keep
+        });
+        self.start_indexes.clear();
+        self.init();
+        debug_assert!(self.assert_invariants());
+    }
+
+    /// Removes all duplicates from a sorted vector.
+    ///
+    /// Since equal elements are adjacent in a sorted vector, this compacts the
+    /// vector in a single pass in logical order, without un-rotating it first.
+    /// If the vector is not sorted, only consecutive repeated elements are
+    /// removed.
+    ///
+    /// If there are no repeats, the internal layout is left untouched.
+    ///
+    /// This is an `O(n)` operation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::RotatedVec;
+    ///
+    /// let mut vec: RotatedVec<_> = vec![3, 1, 2, 1, 3, 3].into();
+    /// vec.sort();
+    /// vec.dedup_sorted();
+    /// assert_eq!(vec, vec![1, 2, 3].into());
+    /// ```
+    pub fn dedup_sorted(&mut self)
+    where
+        T: PartialEq,
+    {
+        let len = self.len();
+        // find the first repeat without disturbing the layout
+        let first_repeat = match (1..len)
+            .find(|&i| self.data[self.get_real_index(i)] == self.data[self.get_real_index(i - 1)])
+        {
+            Some(index) => index,
+            None => return,
+        };
+        // swap each kept element down to the write position, so the repeats
+        // collect past it and are dropped by the final truncation
+        let mut write_index = first_repeat;
+        for read_index in first_repeat + 1..len {
+            let read_real_idx = self.get_real_index(read_index);
+            if self.data[read_real_idx] != self.data[self.get_real_index(write_index - 1)] {
+                let write_real_idx = self.get_real_index(write_index);
+                self.data.swap(write_real_idx, read_real_idx);
+                write_index += 1;
+            }
+        }
+        self.truncate(write_index);
+        debug_assert!(self.assert_invariants());
+    }
+
+    /// Returns the number of element moves performed by `insert` and `remove`
+    /// since the last call, and resets the count to zero.
+    ///
+    /// Each element written to a new slot of the internal layout counts as
+    /// one move, so this can be used to check the `O(√n)` cost of those
+    /// operations empirically. This is only available when the `metrics`
+    /// feature is enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "metrics")] {
+    /// use rotated_vec::RotatedVec;
+    ///
+    /// let mut vec: RotatedVec<_> = vec![1, 2, 3].into();
+    /// vec.push(4);
+    /// assert_eq!(vec.take_move_count(), 1);
+    /// assert_eq!(vec.take_move_count(), 0);
+    /// # }
+    /// ```
+    #[cfg(feature = "metrics")]
+    pub fn take_move_count(&mut self) -> u64 {
+        mem::take(&mut self.move_count)
+    }
+
+    // this returns the index in the backing array of the given logical index
+    fn get_real_index(&self, index: usize) -> usize {
+        debug_assert!(index < self.data.len());
+        let subarray_idx = Self::get_subarray_idx_from_array_idx(index);
+        let subarray_start_idx = Self::get_array_idx_from_subarray_idx(subarray_idx);
+        self.get_real_index_in_subarray(index, subarray_idx, subarray_start_idx)
+    }
+
+    // this returns the index in the backing array of the given logical index,
+    // which must lie in the given subarray
+    fn get_real_index_in_subarray(&self, index: usize, subarray_idx: usize, subarray_start_idx: usize) -> usize {
+        debug_assert!(index < self.data.len());
+        let subarray_len = if subarray_idx == self.start_indexes.len() - 1 {
+            self.data.len() - subarray_start_idx
+        } else {
+            subarray_idx + 1
+        };
+        debug_assert!(index >= subarray_start_idx);
+        let idx_offset = index - subarray_start_idx;
+        let pivot_offset = self.start_indexes[subarray_idx];
+        let rotated_offset = (pivot_offset + idx_offset) % subarray_len;
+        debug_assert!(rotated_offset < subarray_len);
+        subarray_start_idx + rotated_offset
+    }
+
+    // this returns the contiguous index ranges of the backing array covering the logical range
+    // `start..end`, in logical order: at most two ranges per subarray
+    fn real_ranges(&self, start: usize, end: usize) -> impl DoubleEndedIterator<Item = Range<usize>> + '_ {
+        debug_assert!(start <= end && end <= self.data.len());
+        let subarray_range = if start < end {
+            Self::get_subarray_idx_from_array_idx(start)..Self::get_subarray_idx_from_array_idx(end - 1) + 1
+        } else {
+            0..0
+        };
+        subarray_range
+            .flat_map(move |i| {
+                let subarray_start_idx = Self::get_array_idx_from_subarray_idx(i);
+                let subarray_len = min(i + 1, self.data.len() - subarray_start_idx);
+                let pivot_offset = self.start_indexes[i];
+                let pivot_idx = subarray_start_idx + pivot_offset;
+                // logical offsets within this subarray covered by the range: the
+                // elements from the pivot on hold offsets `0..tail_len`, and the
+                // elements before the pivot hold the rest
+                let tail_len = subarray_len - pivot_offset;
+                let lo = start.saturating_sub(subarray_start_idx);
+                let hi = min(end - subarray_start_idx, subarray_len);
+                let tail_range = pivot_idx + min(lo, tail_len)..pivot_idx + min(hi, tail_len);
+                let head_range = subarray_start_idx + lo.saturating_sub(tail_len)
+                    ..subarray_start_idx + hi.saturating_sub(tail_len);
+                once(tail_range).chain(once(head_range))
+            })
+            .filter(|range| !range.is_empty())
+    }
+
+    // like `real_ranges`, but returns the runs of elements themselves
+    fn runs_in_range(&self, start: usize, end: usize) -> impl DoubleEndedIterator<Item = &[T]> + '_ {
+        self.real_ranges(start, end).map(move |range| &self.data[range])
+    }
+
+    fn integer_sum(n: usize)    -> usize {
+        // I learned this from a 10-year-old named Gauss
+        // halve whichever factor is even before multiplying, so the product only
+        // overflows if the sum itself does. that can only happen for the start of the
+        // subarray one past the last, and saturating keeps it an upper bound on `len()`.
+        if n.is_multiple_of(2) {
+            (n / 2).saturating_mul(n + 1)
+        } else {
+            n.saturating_mul(n.div_ceil(2))
+        }
+    }
+
+    fn integer_sum_inverse(n: usize) -> usize {
+        // y = (x * (x + 1)) / 2
+        // x = (sqrt(8 * y + 1) - 1) / 2
+        // an integer square root is exact, unlike going through `f64`
+        if n <= (usize::MAX - 1) / 8 {
+            ((n * 8 + 1).isqrt() - 1) / 2
+        } else {
+            // `8 * y + 1` would overflow, e.g. past 2^29 elements on 32-bit targets,
+            // so widen; the root always fits back into a `usize`
+            ((((n as u128) * 8 + 1).isqrt() - 1) / 2) as usize
+        }
+    }
+
+    fn get_subarray_idx_from_array_idx(idx: usize) -> usize {
+        if idx == 0 {
+            0
+        } else {
+            Self::integer_sum_inverse(idx)
+        }
+    }
+
+    fn get_array_idx_from_subarray_idx(idx: usize) -> usize {
+        if idx == 0 {
+            0
+        } else {
+            Self::integer_sum(idx)
+        }
+    }
+
+    fn is_last_subarray_full(&self) -> bool {
+        self.data.len() == Self::get_array_idx_from_subarray_idx(self.start_indexes.len())
+    }
+
+    fn unrotate_last_subarray(&mut self) {
+        let last_subarray_idx = Self::get_subarray_idx_from_array_idx(self.len() - 1);
+        let last_subarray_start_idx = Self::get_array_idx_from_subarray_idx(last_subarray_idx);
+        let last_subarray_len = if last_subarray_idx == self.start_indexes.len() - 1 {
+            self.len() - last_subarray_start_idx
+        } else {
+            last_subarray_idx + 1
+        };
+        let last_subarray_end_idx = last_subarray_start_idx + last_subarray_len;
+        let last_subarray = &mut self.data[last_subarray_start_idx..last_subarray_end_idx];
+        // un-rotate subarray in-place
+        let pivot_offset = self.start_indexes[last_subarray_idx];
+        last_subarray.rotate_left(pivot_offset);
+        self.start_indexes[last_subarray_idx] = 0;
+    }
+
+    // un-rotates every subarray from `first_subarray_idx` on, in place
+    fn unrotate_subarrays_from(&mut self, first_subarray_idx: usize) {
+        for (i, pivot_offset) in self.start_indexes.iter_mut().enumerate().skip(first_subarray_idx) {
+            let subarray_start_idx = Self::get_array_idx_from_subarray_idx(i);
+            let subarray_end_idx = min(subarray_start_idx + i + 1, self.data.len());
+            let subarray = &mut self.data[subarray_start_idx..subarray_end_idx];
+            // un-rotate subarray in-place
+            subarray.rotate_left(*pivot_offset);
+            *pivot_offset = 0;
+        }
+    }
+
+    // tallies element moves for `take_move_count()`; compiles to nothing without the `metrics` feature
+    #[inline]
+    fn record_moves(&mut self, _count: usize) {
+        #[cfg(feature = "metrics")]
+        {
+            self.move_count += _count as u64;
+        }
+    }
+
+    // number of subarrays beyond the current ones needed to hold `additional` more elements
+    fn additional_subarrays(&self, additional: usize) -> usize {
+        let new_len = self.len() + additional;
+        if new_len == 0 {
+            0
+        } else {
+            Self::get_subarray_idx_from_array_idx(new_len - 1) + 1 - self.start_indexes.len()
+        }
+    }
+
+    // like `assert_invariants()`, but returns false rather than panicking
+    fn is_valid_layout(&self) -> bool {
+        let expected_start_indexes_len = if self.is_empty() {
+            0
+        } else {
+            Self::get_subarray_idx_from_array_idx(self.len() - 1) + 1
+        };
+        self.start_indexes.len() == expected_start_indexes_len
+            && self
+                .start_indexes
+                .iter()
+                .enumerate()
+                .all(|(idx, &offset)| offset <= idx)
+            && (self.is_last_subarray_full() || self.start_indexes.last() == Some(&0))
+    }
+
+    #[inline(always)]
+    fn assert_invariants(&self) -> bool {
+        // assert offset array has proper length
+        let expected_start_indexes_len = if self.is_empty() {
+            0
+        } else {
+            Self::get_subarray_idx_from_array_idx(self.len() - 1) + 1
+        };
+        assert_eq!(self.start_indexes.len(), expected_start_indexes_len);
+        // assert index of each subarray's first element lies within the subarray
+        assert!(self
+            .start_indexes
+            .iter()
+            .enumerate()
+            .all(|(idx, &offset)| offset <= idx));
+        true
+    }
+
+    // given data array, initialize offset array
+    fn init(&mut self) {
+        debug_assert!(self.start_indexes.is_empty());
+        if !self.data.is_empty() {
+            let last_subarray_idx = Self::get_subarray_idx_from_array_idx(self.data.len() - 1);
+            // resize rather than replace, so any capacity already reserved is used
+            self.start_indexes.resize(last_subarray_idx + 1, 0);
+        }
+    }
+}
+
+impl<T> RotatedVec<T>
+where
+    T: Copy + Default + Debug,
+{
+    /// Returns a reference to the value in the array at the given index, or
+    /// `default` if the index is out of bounds.
+    ///
+    /// This is a constant-time operation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::RotatedVec;
+    ///
+    /// let vec: RotatedVec<_> = vec![1, 2, 3].into();
+    /// assert_eq!(vec.get_or(0, &0), &1);
+    /// assert_eq!(vec.get_or(3, &0), &0);
+    /// ```
+    pub fn get_or<'a>(&'a self, index: usize, default: &'a T) -> &'a T {
+        self.get(index).unwrap_or(default)
+    }
+
+    /// Returns a copy of the value in the array at the given index, or the
+    /// result of calling `f` if the index is out of bounds.
+    ///
+    /// This is a constant-time operation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::RotatedVec;
+    ///
+    /// let vec: RotatedVec<_> = vec![1, 2, 3].into();
+    /// assert_eq!(vec.get_cloned_or(0, || 0), 1);
+    /// assert_eq!(vec.get_cloned_or(3, || 0), 0);
+    /// ```
+    pub fn get_cloned_or<F>(&self, index: usize, f: F) -> T
+    where
+        F: FnOnce() -> T,
+    {
+        self.get(index).cloned().unwrap_or_else(f)
+    }
+
+    /// Swaps two elements in the vector.
+    ///
+    /// This is a constant-time operation.
+    ///
+    /// # Arguments
+    ///
+    /// * a - The index of the first element
+    /// * b - The index of the second element
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` or `b` are out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::RotatedVec;
+    ///
+    /// let mut vec: RotatedVec<_> = vec!["a", "b", "c", "d"].into();
+    /// vec.swap(1, 3);
+    /// assert_eq!(vec, vec!["a", "d", "c", "b"].into());
+    /// ```
+    pub fn swap(&mut self, a: usize, b: usize) {
+        assert!(a < self.len() && b < self.len());
+        let real_a = self.get_real_index(a);
+        let real_b = self.get_real_index(b);
+        self.data.swap(real_a, real_b);
+    }
+
+    /// Returns mutable references to two distinct elements in the vector, or
+    /// `None` if `a == b` or either index is out of bounds.
+    ///
+    /// This is a constant-time operation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::RotatedVec;
+    ///
+    /// let mut vec: RotatedVec<_> = vec![1, 2, 3].into();
+    /// if let Some((a, b)) = vec.pair_mut(0, 2) {
+    ///     *a += 10;
+    ///     *b += 20;
+    /// }
+    /// assert_eq!(vec, vec![11, 2, 23].into());
+    /// assert!(vec.pair_mut(1, 1).is_none());
+    /// assert!(vec.pair_mut(1, 3).is_none());
+    /// ```
+    pub fn pair_mut(&mut self, a: usize, b: usize) -> Option<(&mut T, &mut T)> {
+        if a == b || a >= self.len() || b >= self.len() {
+            return None;
+        }
+        let real_a = self.get_real_index(a);
+        let real_b = self.get_real_index(b);
+        debug_assert!(real_a != real_b);
+        // split the data array between the two physical slots
+        if real_a < real_b {
+            let (head, tail) = self.data.split_at_mut(real_b);
+            Some((&mut head[real_a], &mut tail[0]))
+        } else {
+            let (head, tail) = self.data.split_at_mut(real_a);
+            Some((&mut tail[0], &mut head[real_b]))
+        }
+    }
+
+    /// Copies all elements from `src` into the logical range `range` of `self`.
+    ///
+    /// This is an `O(k)` operation, where `k` is the length of `src`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` is out of bounds or if its length differs from the
+    /// length of `src`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::RotatedVec;
+    ///
+    /// let mut vec: RotatedVec<_> = vec![1, 2, 3, 4, 5].into();
+    /// vec.copy_from_slice(1..3, &[7, 8]);
+    /// assert_eq!(vec, vec![1, 7, 8, 4, 5].into());
+    /// ```
+    pub fn copy_from_slice(&mut self, range: Range<usize>, src: &[T]) {
+        assert!(range.start <= range.end && range.end <= self.len());
+        assert_eq!(
+            range.len(),
+            src.len(),
+            "destination and source slices have different lengths"
+        );
+        for (index, &value) in range.zip(src) {
+            let real_idx = self.get_real_index(index);
+            self.data[real_idx] = value;
+        }
+    }
+
+    /// Swaps all elements in `self` with those in `other`, in logical order.
+    ///
+    /// This is an `O(n)` operation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `other` differs from the length of `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::RotatedVec;
+    ///
+    /// let mut vec: RotatedVec<_> = vec![1, 2, 3].into();
+    /// let mut slice = [4, 5, 6];
+    /// vec.swap_with_slice(&mut slice);
+    /// assert_eq!(vec, vec![4, 5, 6].into());
+    /// assert_eq!(slice, [1, 2, 3]);
+    /// ```
+    pub fn swap_with_slice(&mut self, other: &mut [T]) {
+        assert_eq!(
+            self.len(),
+            other.len(),
+            "destination and source slices have different lengths"
+        );
+        for (index, value) in other.iter_mut().enumerate() {
+            let real_idx = self.get_real_index(index);
+            mem::swap(&mut self.data[real_idx], value);
+        }
+    }
+
+    /// Removes the first `n` elements of the vector, keeping the rest.
+    ///
+    /// This is an `O(n)` operation.
+    ///
+    /// If `n` is greater than or equal to the vector's current length, this
+    /// is equivalent to calling the [`clear`] method.
+    ///
+    /// Note that this method has no effect on the allocated capacity
+    /// of the vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::RotatedVec;
+    ///
+    /// let mut vec: RotatedVec<_> = vec![1, 2, 3, 4, 5].into();
+    /// vec.truncate_front(2);
+    /// assert_eq!(vec, vec![3, 4, 5].into());
+    /// ```
+    ///
+    /// [`clear`]: #method.clear
+    pub fn truncate_front(&mut self, n: usize) {
+        if n >= self.len() {
+            self.clear();
+            return;
+        }
+        self.normalize();
+        self.data.drain(..n);
+        self.start_indexes.clear();
+        self.init();
+        debug_assert!(self.assert_invariants());
+    }
+
+    /// Gets an iterator that visits the values in the `RotatedVec` in order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::RotatedVec;
+    ///
+    /// let vec: RotatedVec<usize> = vec![1, 2, 3].into();
+    /// let mut iter = vec.iter();
+    /// assert_eq!(iter.next(), Some(&1));
+    /// assert_eq!(iter.next(), Some(&2));
+    /// assert_eq!(iter.next(), Some(&3));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            container: self,
+            next_index: 0,
+            end_index: self.len(),
+        }
+    }
+
+    /// Gets an iterator that visits the values in the `RotatedVec` in reverse
+    /// order.
+    ///
+    /// This is equivalent to `iter().rev()`, and remains double-ended, so
+    /// calling `next_back()` on it visits the values in order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::RotatedVec;
+    ///
+    /// let vec: RotatedVec<usize> = vec![1, 2, 3].into();
+    /// let mut iter = vec.rev_iter();
+    /// assert_eq!(iter.next(), Some(&3));
+    /// assert_eq!(iter.next_back(), Some(&1));
+    /// assert_eq!(iter.next(), Some(&2));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    pub fn rev_iter(&self) -> Rev<Iter<'_, T>> {
+        self.iter().rev()
+    }
+
+    /// Gets an iterator that visits the values in the `RotatedVec` in order,
+    /// paired with their logical indexes.
+    ///
+    /// Unlike `iter().enumerate()`, each value carries its true logical index
+    /// even when the iterator is consumed from the back.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::RotatedVec;
+    ///
+    /// let vec: RotatedVec<char> = vec!['a', 'b', 'c'].into();
+    /// let mut iter = vec.logical_enumerate();
+    /// assert_eq!(iter.next_back(), Some((2, &'c')));
+    /// assert_eq!(iter.next(), Some((0, &'a')));
+    /// assert_eq!(iter.next(), Some((1, &'b')));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    pub fn logical_enumerate(&self) -> Zip<Range<usize>, Iter<'_, T>> {
+        (0..self.len()).zip(self.iter())
+    }
+
+    /// Gets an iterator that visits the values in the `RotatedVec` in order,
+    /// starting at index `start`.
+    ///
+    /// If `start` is greater than or equal to the length of the vector, the
+    /// iterator is empty.
+    ///
+    /// This is a constant-time operation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::RotatedVec;
+    ///
+    /// let vec: RotatedVec<usize> = vec![1, 2, 3].into();
+    /// let mut iter = vec.iter_from(1);
+    /// assert_eq!(iter.next(), Some(&2));
+    /// assert_eq!(iter.next(), Some(&3));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    pub fn iter_from(&self, start: usize) -> Iter<'_, T> {
+        Iter {
+            container: self,
+            next_index: min(start, self.len()),
+            end_index: self.len(),
+        }
+    }
+
+    /// Gets an iterator that visits the values in the given logical range of
+    /// the `RotatedVec`, in order.
+    ///
+    /// This is a constant-time operation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is decreasing or extends past the end of the vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::RotatedVec;
+    ///
+    /// let vec: RotatedVec<usize> = vec![1, 2, 3, 4].into();
+    /// assert!(vec.range(1..3).eq([2, 3].iter()));
+    /// assert!(vec.range(..=1).eq([1, 2].iter()));
+    /// assert!(vec.range(2..).eq([3, 4].iter()));
+    /// ```
+    pub fn range<R>(&self, range: R) -> Iter<'_, T>
+    where
+        R: RangeBounds<usize>,
+    {
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start
+                .checked_add(1)
+                .expect("attempted to index vector from after maximum usize"),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end
+                .checked_add(1)
+                .expect("attempted to index vector up to maximum usize"),
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => self.len(),
+        };
+        assert!(start <= end && end <= self.len());
+        Iter {
+            container: self,
+            next_index: start,
+            end_index: end,
+        }
+    }
+
+    /// Gets an iterator that yields copies of the values in the `RotatedVec`
+    /// in order.
+    ///
+    /// This is equivalent to `iter().copied()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::RotatedVec;
+    ///
+    /// let vec: RotatedVec<usize> = vec![1, 2, 3].into();
+    /// let doubled: Vec<usize> = vec.copied().map(|x| x * 2).collect();
+    /// assert_eq!(doubled, vec![2, 4, 6]);
+    /// ```
+    pub fn copied(&self) -> Copied<Iter<'_, T>> {
+        self.iter().copied()
+    }
+
+    /// Gets a mutable iterator that visits the values in the `RotatedVec` in order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::RotatedVec;
+    ///
+    /// let mut vec: RotatedVec<usize> = vec![1, 2, 3].into();
+    /// let mut iter = vec.iter_mut();
+    /// let mut current_elem = None;
+    /// current_elem = iter.next();
+    /// assert_eq!(current_elem, Some(&mut 1));
+    /// *current_elem.unwrap() = 2;
+    /// current_elem = iter.next();
+    /// assert_eq!(current_elem, Some(&mut 2));
+    /// *current_elem.unwrap() = 3;
+    /// current_elem = iter.next();
+    /// assert_eq!(current_elem, Some(&mut 3));
+    /// *current_elem.unwrap() = 4;
+    /// assert_eq!(iter.next(), None);
+    /// assert_eq!(vec, vec![2, 3, 4].into());
+    /// ```
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        let len = self.len();
+        let data = self.data.as_mut_ptr();
+        IterMut {
+            container: self,
+            data,
+            next_index: 0,
+            end_index: len,
+            marker: PhantomData,
+        }
+    }
+
+    /// Replaces each element of the vector with the result of calling `f` on it.
+    ///
+    /// Since the mapping is element-wise, this works directly on the backing
+    /// storage, so `f` is called exactly once per element but not necessarily
+    /// in logical order.
+    ///
+    /// This is an `O(n)` operation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::RotatedVec;
+    ///
+    /// let mut vec: RotatedVec<_> = vec![1, 2, 3].into();
+    /// vec.map_in_place(|x| x * 2);
+    /// assert_eq!(vec, vec![2, 4, 6].into());
+    /// ```
+    pub fn map_in_place<F>(&mut self, mut f: F)
+    where
+        F: FnMut(T) -> T,
+    {
+        for x in self.data.iter_mut() {
+            *x = f(*x);
+        }
+    }
+
+    /// Returns a new `RotatedVec` containing the result of calling `f` on each
+    /// element of the vector, in logical order.
+    ///
+    /// This is the analog of `Iterator::map` that returns a `RotatedVec`
+    /// directly. Unlike [`map_in_place`], `f` is called in logical order.
+    ///
+    /// This is an `O(n)` operation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::RotatedVec;
+    ///
+    /// let vec: RotatedVec<u8> = vec![1, 2, 3].into();
+    /// let wide: RotatedVec<u32> = vec.map(|&x| u32::from(x) * 1000);
+    /// assert_eq!(wide, vec![1000, 2000, 3000].into());
+    /// ```
+    ///
+    /// [`map_in_place`]: #method.map_in_place
+    pub fn map<U, F>(&self, f: F) -> RotatedVec<U>
+    where
+        U: Copy + Default + Debug,
+        F: FnMut(&T) -> U,
+    {
+        let data: Vec<U> = self.iter().map(f).collect();
+        RotatedVec::from(data)
+    }
+
+    /// Returns the contents of the vector as a slice, if it is already stored
+    /// in logical order, or `None` if any subarray is rotated.
+    ///
+    /// This is an `O(√n)` operation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::RotatedVec;
+    ///
+    /// let mut vec: RotatedVec<_> = vec![1, 2, 3, 4].into();
+    /// assert_eq!(vec.as_logical_slice(), Some(&[1, 2, 3, 4][..]));
+    /// vec.insert(0, 0);
+    /// assert_eq!(vec.as_logical_slice(), None);
+    /// ```
+    pub fn as_logical_slice(&self) -> Option<&[T]> {
+        if self.start_indexes.iter().all(|&pivot_offset| pivot_offset == 0) {
+            Some(&self.data)
+        } else {
+            None
+        }
+    }
+
+    /// Un-rotates every subarray and releases unused capacity, leaving the
+    /// vector in its most compact and cache-friendly layout.
+    ///
+    /// This combines [`normalize`] and [`shrink_to_fit`]. Afterward, scanning
+    /// the vector in logical order reads the backing storage sequentially.
+    ///
+    /// This is an `O(n)` operation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::RotatedVec;
+    ///
+    /// let mut vec: RotatedVec<_> = RotatedVec::with_capacity(10);
+    /// vec.extend([1, 2, 3, 4]);
+    /// vec.insert(0, 0);
+    /// vec.compact();
+    /// assert_eq!(vec.as_logical_slice(), Some(&[0, 1, 2, 3, 4][..]));
+    /// assert!(vec.capacity() >= 5);
+    /// ```
+    ///
+    /// [`normalize`]: #method.normalize
+    /// [`shrink_to_fit`]: #method.shrink_to_fit
+    pub fn compact(&mut self) {
+        self.normalize();
+        self.shrink_to_fit();
+    }
+
+    /// Returns the first element and a view over the rest of the vector, or
+    /// `None` if it is empty.
+    ///
+    /// This is a constant-time operation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::RotatedVec;
+    ///
+    /// let vec: RotatedVec<_> = vec![1, 2, 3].into();
+    /// let (first, rest) = vec.split_first().unwrap();
+    /// assert_eq!(first, &1);
+    /// assert!(rest.iter().eq([2, 3].iter()));
+    /// ```
+    pub fn split_first(&self) -> Option<(&T, RotatedSlice<'_, T>)> {
+        let first = self.get(0)?;
+        let rest = RotatedSlice {
+            container: self,
+            start_index: 1,
+            end_index: self.len(),
+        };
+        Some((first, rest))
+    }
+
+    /// Returns the last element and a view over the rest of the vector, or
+    /// `None` if it is empty.
+    ///
+    /// This is a constant-time operation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::RotatedVec;
+    ///
+    /// let vec: RotatedVec<_> = vec![1, 2, 3].into();
+    /// let (last, rest) = vec.split_last().unwrap();
+    /// assert_eq!(last, &3);
+    /// assert!(rest.iter().eq([1, 2].iter()));
+    /// ```
+    pub fn split_last(&self) -> Option<(&T, RotatedSlice<'_, T>)> {
+        let last = self.get(self.len().checked_sub(1)?)?;
+        let rest = RotatedSlice {
+            container: self,
+            start_index: 0,
+            end_index: self.len() - 1,
+        };
+        Some((last, rest))
+    }
+
+    /// Returns a new vector containing a copy of the elements in `range`.
+    ///
+    /// This is an `O(k)` operation, where `k` is the length of `range`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is decreasing or extends past the end of the vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::RotatedVec;
+    ///
+    /// let vec: RotatedVec<_> = vec![1, 2, 3, 4, 5].into();
+    /// assert_eq!(vec.slice_to_owned(1..4), vec![2, 3, 4].into());
+    /// assert!(vec.slice_to_owned(2..2).is_empty());
+    /// ```
+    pub fn slice_to_owned(&self, range: Range<usize>) -> RotatedVec<T> {
+        assert!(range.start <= range.end && range.end <= self.len());
+        range.map(|i| self[i]).collect()
+    }
+
+    /// Returns an iterator over `chunk_size` elements of the vector at a time,
+    /// starting at the end of the vector.
+    ///
+    /// The chunks are views and do not overlap. If `chunk_size` does not divide
+    /// the length of the vector, then the last chunk will not have length
+    /// `chunk_size`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::RotatedVec;
+    ///
+    /// let vec: RotatedVec<_> = vec!['l', 'o', 'r', 'e', 'm'].into();
+    /// let mut iter = vec.rchunks(2);
+    /// assert!(iter.next().unwrap().iter().eq(['e', 'm'].iter()));
+    /// assert!(iter.next().unwrap().iter().eq(['o', 'r'].iter()));
+    /// assert!(iter.next().unwrap().iter().eq(['l'].iter()));
+    /// assert!(iter.next().is_none());
+    /// ```
+    pub fn rchunks(&self, chunk_size: usize) -> RChunks<'_, T> {
+        assert!(chunk_size != 0);
+        RChunks {
+            container: self,
+            start_index: 0,
+            end_index: self.len(),
+            chunk_size,
+        }
+    }
+
+    /// Returns an iterator over `chunk_size` elements of the vector at a time,
+    /// starting at the beginning of the vector, with mutable access to each
+    /// element.
+    ///
+    /// The chunks do not overlap, so they can be processed independently. If
+    /// `chunk_size` does not divide the length of the vector, then the last
+    /// chunk will not have length `chunk_size`. Since the elements of a chunk
+    /// may be scattered across rotated subarrays, each chunk is a `Vec` of
+    /// mutable references rather than a slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::RotatedVec;
+    ///
+    /// let mut vec: RotatedVec<_> = vec![1, 2, 3, 4, 5].into();
+    /// for (i, chunk) in vec.chunks_mut(2).enumerate() {
+    ///     for x in chunk {
+    ///         *x *= 10 * (i + 1);
+    ///     }
+    /// }
+    /// assert_eq!(vec, vec![10, 20, 60, 80, 150].into());
+    /// ```
+    pub fn chunks_mut(&mut self, chunk_size: usize) -> ChunksMut<'_, T> {
+        assert!(chunk_size != 0);
+        ChunksMut {
+            iter: self.iter_mut(),
+            chunk_size,
+        }
+    }
+
+    /// Returns an iterator over the vector producing non-overlapping runs of
+    /// elements, using the predicate to separate them.
+    ///
+    /// The predicate is called on each pair of logically adjacent elements,
+    /// and a new run starts between them whenever it returns `false`. Since
+    /// the elements of a run may be scattered across rotated subarrays, each
+    /// run is yielded as an owned `Vec`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::RotatedVec;
+    ///
+    /// let vec: RotatedVec<_> = vec![1, 1, 1, 3, 3, 2, 2, 2].into();
+    /// let mut iter = vec.group_by(|a, b| a == b);
+    /// assert_eq!(iter.next(), Some(vec![1, 1, 1]));
+    /// assert_eq!(iter.next(), Some(vec![3, 3]));
+    /// assert_eq!(iter.next(), Some(vec![2, 2, 2]));
+    /// assert_eq!(iter.next(), None);
+    ///
+    /// let vec: RotatedVec<_> = vec![1, 2, 3, 2, 3, 4].into();
+    /// let runs: Vec<_> = vec.group_by(|a, b| a < b).collect();
+    /// assert_eq!(runs, vec![vec![1, 2, 3], vec![2, 3, 4]]);
+    /// ```
+    pub fn group_by<F>(&self, same_group: F) -> GroupBy<'_, T, F>
+    where
+        F: FnMut(&T, &T) -> bool,
+    {
+        GroupBy {
+            container: self,
+            same_group,
+            index: 0,
+        }
+    }
+
+    /// Returns `true` if the `RotatedVec` contains an element equal to the
+    /// given value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::RotatedVec;
+    ///
+    /// let mut vec = RotatedVec::new();
+    ///
+    /// vec.push(0);
+    /// vec.push(1);
+    ///
+    /// assert_eq!(vec.contains(&1), true);
+    /// assert_eq!(vec.contains(&10), false);
+    /// ```
+    pub fn contains(&self, x: &T) -> bool
+        where T: PartialEq<T>
+    {
+        self.data.contains(x)
+    }
+
+    /// Returns `true` if the `RotatedVec` contains an element equal to every
+    /// item of `items`.
+    ///
+    /// An empty query is trivially contained. Each item is searched for
+    /// linearly, so this is an `O(nk)` operation, where `k` is the number of
+    /// items. For a sorted vector, prefer [`contains_all_sorted`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::RotatedVec;
+    ///
+    /// let vec: RotatedVec<_> = vec![3, 1, 4, 1, 5].into();
+    /// assert!(vec.contains_all(&[1, 5]));
+    /// assert!(!vec.contains_all(&[1, 2]));
+    /// assert!(vec.contains_all(&[]));
+    /// ```
+    ///
+    /// [`contains_all_sorted`]: #method.contains_all_sorted
+    pub fn contains_all<'a, I: IntoIterator<Item = &'a T>>(&self, items: I) -> bool
+    where
+        T: PartialEq + 'a,
+    {
+        items.into_iter().all(|x| self.contains(x))
+    }
+
+    /// Returns `true` if the sorted `RotatedVec` contains an element equal to
+    /// every item of `items`.
+    ///
+    /// Each item is looked up with [`binary_search`], so this is an
+    /// `O(k log n)` operation, where `k` is the number of items. If the vector
+    /// is not sorted, the result is unspecified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::RotatedVec;
+    ///
+    /// let vec: RotatedVec<_> = vec![1, 1, 3, 4, 5].into();
+    /// assert!(vec.contains_all_sorted(&[5, 1]));
+    /// assert!(!vec.contains_all_sorted(&[1, 2]));
+    /// assert!(vec.contains_all_sorted(&[]));
+    /// ```
+    ///
+    /// [`binary_search`]: #method.binary_search
+    pub fn contains_all_sorted<'a, I: IntoIterator<Item = &'a T>>(&self, items: I) -> bool
+    where
+        T: Ord + 'a,
+    {
+        items.into_iter().all(|x| self.binary_search(x).is_ok())
+    }
+
+    /// Returns `true` if `needle` is a prefix of the vector.
+    ///
+    /// This is an `O(m)` operation, where `m` is the length of `needle`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::RotatedVec;
+    ///
+    /// let vec: RotatedVec<_> = vec![10, 40, 30].into();
+    /// assert!(vec.starts_with(&[10]));
+    /// assert!(vec.starts_with(&[10, 40]));
+    /// assert!(!vec.starts_with(&[50]));
+    /// assert!(!vec.starts_with(&[10, 50]));
+    /// ```
+    ///
+    /// Always returns `true` if `needle` is an empty slice:
+    ///
+    /// ```
+    /// use rotated_vec::RotatedVec;
+    ///
+    /// let vec: RotatedVec<_> = vec![10, 40, 30].into();
+    /// assert!(vec.starts_with(&[]));
+    /// let vec: RotatedVec<u8> = RotatedVec::new();
+    /// assert!(vec.starts_with(&[]));
+    /// ```
+    pub fn starts_with(&self, needle: &[T]) -> bool
+    where
+        T: PartialEq,
+    {
+        needle.len() <= self.len()
+            && needle.iter().enumerate().all(|(i, x)| self[i] == *x)
+    }
+
+    /// Returns `true` if `needle` is a suffix of the vector.
+    ///
+    /// This is an `O(m)` operation, where `m` is the length of `needle`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::RotatedVec;
+    ///
+    /// let vec: RotatedVec<_> = vec![10, 40, 30].into();
+    /// assert!(vec.ends_with(&[30]));
+    /// assert!(vec.ends_with(&[40, 30]));
+    /// assert!(!vec.ends_with(&[50]));
+    /// assert!(!vec.ends_with(&[50, 30]));
+    /// ```
+    ///
+    /// Always returns `true` if `needle` is an empty slice:
+    ///
+    /// ```
+    /// use rotated_vec::RotatedVec;
+    ///
+    /// let vec: RotatedVec<_> = vec![10, 40, 30].into();
+    /// assert!(vec.ends_with(&[]));
+    /// let vec: RotatedVec<u8> = RotatedVec::new();
+    /// assert!(vec.ends_with(&[]));
+    /// ```
+    pub fn ends_with(&self, needle: &[T]) -> bool
+    where
+        T: PartialEq,
+    {
+        needle.len() <= self.len() && {
+            let offset = self.len() - needle.len();
+            needle.iter().enumerate().all(|(i, x)| self[offset + i] == *x)
+        }
+    }
+
+    /// Binary searches this sorted vector for a given element.
+    ///
+    /// If the value is found then `Ok` is returned, containing the index of
+    /// the matching element; if the value is not found then `Err` is
+    /// returned, containing the index where a matching element could be
+    /// inserted while maintaining sorted order. If there are multiple
+    /// matches, then any one of the matches could be returned.
+    ///
+    /// This is an `O(log n)` operation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::RotatedVec;
+    ///
+    /// let vec: RotatedVec<_> = vec![0, 1, 1, 1, 1, 2, 3, 5, 8, 13, 21, 34, 55].into();
+    ///
+    /// assert_eq!(vec.binary_search(&13), Ok(9));
+    /// assert_eq!(vec.binary_search(&4), Err(7));
+    /// assert_eq!(vec.binary_search(&100), Err(13));
+    /// let r = vec.binary_search(&1);
+    /// assert!(match r { Ok(1..=4) => true, _ => false, });
+    /// ```
+    pub fn binary_search(&self, x: &T) -> Result<usize, usize>
+    where
+        T: Ord,
+    {
+        self.binary_search_by(|probe| probe.cmp(x))
+    }
+
+    /// Binary searches this sorted vector with a comparator function.
+    ///
+    /// The comparator function should implement an order consistent with the
+    /// sort order of the vector, returning an order code that indicates
+    /// whether its argument is `Less`, `Equal` or `Greater` than the desired
+    /// target. Return values are as for [`binary_search`].
+    ///
+    /// This is an `O(log n)` operation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::RotatedVec;
+    ///
+    /// let vec: RotatedVec<_> = vec![0, 1, 1, 1, 1, 2, 3, 5, 8, 13, 21, 34, 55].into();
+    ///
+    /// assert_eq!(vec.binary_search_by(|probe| probe.cmp(&13)), Ok(9));
+    /// assert_eq!(vec.binary_search_by(|probe| probe.cmp(&4)), Err(7));
+    /// ```
+    ///
+    /// [`binary_search`]: #method.binary_search
+    pub fn binary_search_by<F>(&self, mut f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&T) -> Ordering,
+    {
+        let mut low = 0;
+        let mut high = self.len();
+        while low < high {
+            let mid = low + (high - low) / 2;
+            match f(&self[mid]) {
+                Ordering::Less => low = mid + 1,
+                Ordering::Greater => high = mid,
+                Ordering::Equal => return Ok(mid),
+            }
+        }
+        Err(low)
+    }
+
+    /// Binary searches this sorted vector with a key extraction function.
+    ///
+    /// Assumes that the vector is sorted by the key extracted with the same
+    /// function. Return values are as for [`binary_search`].
+    ///
+    /// This is an `O(log n)` operation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::RotatedVec;
+    ///
+    /// let vec: RotatedVec<_> = vec![(0, 0), (2, 1), (4, 1), (5, 1), (3, 1),
+    ///          (1, 2), (2, 3), (4, 5), (5, 8), (3, 13),
+    ///          (1, 21), (2, 34), (4, 55)].into();
+    ///
+    /// assert_eq!(vec.binary_search_by_key(&13, |&(a, b)| b), Ok(9));
+    /// assert_eq!(vec.binary_search_by_key(&4, |&(a, b)| b), Err(7));
+    /// assert_eq!(vec.binary_search_by_key(&100, |&(a, b)| b), Err(13));
+    /// ```
+    ///
+    /// [`binary_search`]: #method.binary_search
+    pub fn binary_search_by_key<B, F>(&self, b: &B, mut f: F) -> Result<usize, usize>
+    where
+        B: Ord,
+        F: FnMut(&T) -> B,
+    {
+        self.binary_search_by(|k| f(k).cmp(b))
+    }
+
+    /// Returns the index of the element of this sorted vector nearest to `x`
+    /// in rank, or `None` if the vector is empty.
+    ///
+    /// If `x` is present, the index of a matching element is returned.
+    /// Otherwise `x` falls between two adjacent elements, and the lower of
+    /// them is chosen; if `x` is less than every element, the first element
+    /// is chosen.
+    ///
+    /// This is an `O(log n)` operation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::RotatedVec;
+    ///
+    /// let vec: RotatedVec<_> = vec![10, 20, 30].into();
+    /// assert_eq!(vec.nearest(&20), Some(1));
+    /// assert_eq!(vec.nearest(&25), Some(1));
+    /// assert_eq!(vec.nearest(&5), Some(0));
+    /// assert_eq!(vec.nearest(&35), Some(2));
+    /// assert_eq!(RotatedVec::<i32>::new().nearest(&5), None);
+    /// ```
+    pub fn nearest(&self, x: &T) -> Option<usize>
+    where
+        T: Ord,
+    {
+        if self.is_empty() {
+            return None;
+        }
+        match self.binary_search(x) {
+            Ok(i) => Some(i),
+            Err(i) => Some(i.saturating_sub(1)),
+        }
+    }
+
+    /// Returns a view over all elements of this sorted vector that are greater
+    /// than or equal to `lo` and less than `hi`.
+    ///
+    /// The bounds of the view are found by binary search, so this supports
+    /// range scans without visiting the whole vector. If `hi <= lo`, the view
+    /// is empty. If the vector is not sorted, the result is unspecified.
+    ///
+    /// This is an `O(log n)` operation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::RotatedVec;
+    ///
+    /// let vec: RotatedVec<_> = vec![1, 2, 2, 3, 5, 8].into();
+    /// assert!(vec.range_query(&2, &5).iter().eq([2, 2, 3].iter()));
+    /// assert!(vec.range_query(&4, &5).is_empty());
+    /// ```
+    pub fn range_query(&self, lo: &T, hi: &T) -> RotatedSlice<'_, T>
+    where
+        T: Ord,
+    {
+        let start_index = self.partition_point(|x| x < lo);
+        let end_index = max(start_index, self.partition_point(|x| x < hi));
+        RotatedSlice {
+            container: self,
+            start_index,
+            end_index,
+        }
+    }
+
+    /// Returns the index of the partition point according to the given predicate
+    /// (the index of the first element of the second partition).
+    ///
+    /// The vector is assumed to be partitioned according to the given predicate.
+    /// This means that all elements for which the predicate returns true are at the start of the vector
+    /// and all elements for which the predicate returns false are at the end.
+    /// If the vector is not partitioned, the returned result is unspecified and meaningless.
+    ///
+    /// This is an `O(log n)` operation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::RotatedVec;
+    ///
+    /// let vec: RotatedVec<_> = vec![1, 2, 3, 3, 5, 6, 7].into();
+    /// let i = vec.partition_point(|&x| x < 5);
+    /// assert_eq!(i, 4);
+    /// ```
+    pub fn partition_point<P>(&self, mut pred: P) -> usize
+    where
+        P: FnMut(&T) -> bool,
+    {
+        let mut low = 0;
+        let mut high = self.len();
+        while low < high {
+            let mid = low + (high - low) / 2;
+            if pred(&self[mid]) {
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+        low
+    }
+
+    /// Creates an iterator which uses a closure to determine if an element
+    /// should be removed.
+    ///
+    /// If the closure returns true, the element is removed and yielded. If the
+    /// closure returns false, the element remains in the vector and is not
+    /// yielded by the iterator. The closure may mutate any element it examines.
+    ///
+    /// If the returned `ExtractIf` is dropped before being fully consumed, the
+    /// remaining elements are retained.
+    ///
+    /// This is an `O(n)` operation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::RotatedVec;
+    ///
+    /// let mut vec: RotatedVec<_> = vec![1, 2, 3, 4, 5, 6].into();
+    /// let odds: Vec<_> = vec.extract_if(|x| *x % 2 == 1).collect();
+    /// assert_eq!(odds, vec![1, 3, 5]);
+    /// assert_eq!(vec, vec![2, 4, 6].into());
+    /// ```
+    pub fn extract_if<F>(&mut self, pred: F) -> ExtractIf<'_, T, F>
+    where
+        F: FnMut(&mut T) -> bool,
+    {
+        // operate directly on the data array in logical order
+        self.normalize();
+        ExtractIf {
+            container: self,
+            pred,
+            read_index: 0,
+            write_index: 0,
+        }
+    }
+
+    /// Consumes the vector, splitting it into two vectors: the first holding
+    /// all elements for which the predicate returns true, and the second
+    /// holding all elements for which it returns false.
+    ///
+    /// Both vectors preserve the relative order of their elements.
+    ///
+    /// This is an `O(n)` operation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::RotatedVec;
+    ///
+    /// let vec: RotatedVec<_> = vec![1, 2, 3, 4, 5, 6].into();
+    /// let (evens, odds) = vec.partition(|x| *x % 2 == 0);
+    /// assert_eq!(evens, vec![2, 4, 6].into());
+    /// assert_eq!(odds, vec![1, 3, 5].into());
+    /// ```
+    pub fn partition<F>(self, mut pred: F) -> (RotatedVec<T>, RotatedVec<T>)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let mut matching = Vec::new();
+        let mut non_matching = Vec::new();
+        for &x in self.contiguous_runs().flatten() {
+            if pred(&x) {
+                matching.push(x);
+            } else {
+                non_matching.push(x);
+            }
+        }
+        (matching.into(), non_matching.into())
+    }
+
+    /// Returns a reference to the first element in logical order that
+    /// satisfies the predicate, or `None` if there is no such element.
+    ///
+    /// This is an `O(n)` operation.
     ///
-    /// No truncation occurs when `len` is greater than the vector's current
-    /// length:
+    /// # Examples
     ///
     /// ```
     /// use rotated_vec::RotatedVec;
     ///
-    /// let mut vec: RotatedVec<_> = vec![1, 2, 3].into();
-    /// vec.truncate(8);
-    /// assert_eq!(vec, vec![1, 2, 3].into());
+    /// let vec: RotatedVec<_> = vec![1, 2, 3, 4].into();
+    /// assert_eq!(vec.find(|&x| x % 2 == 0), Some(&2));
+    /// assert_eq!(vec.find(|&x| x > 4), None);
     /// ```
+    pub fn find<P>(&self, mut pred: P) -> Option<&T>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.iter().find(|x| pred(x))
+    }
+
+    /// Returns a reference to the last element in logical order that
+    /// satisfies the predicate, or `None` if there is no such element.
     ///
-    /// Truncating when `len == 0` is equivalent to calling the [`clear`]
-    /// method.
+    /// This is an `O(n)` operation.
+    ///
+    /// # Examples
     ///
     /// ```
     /// use rotated_vec::RotatedVec;
     ///
-    /// let mut vec: RotatedVec<_> = vec![1, 2, 3].into();
-    /// vec.truncate(0);
-    /// assert_eq!(vec, vec![].into());
+    /// let vec: RotatedVec<_> = vec![1, 2, 3, 4].into();
+    /// assert_eq!(vec.rfind(|&x| x % 2 == 1), Some(&3));
+    /// assert_eq!(vec.rfind(|&x| x > 4), None);
     /// ```
-    ///
-    pub fn truncate(&mut self, len: usize) {
-        if len >= self.len() {
-            return
-        }
-        // conceptually, we drop all subarrays after the truncated length,
-        // then un-rotate the new last subarray, then drop any remaining elements.
-        self.unrotate_last_subarray();
-         // drop subarrays after truncated length
-        let last_subarray_idx = Self::get_subarray_idx_from_array_idx(self.len() - 1);
-        self.start_indexes.truncate(last_subarray_idx + 1);
-        // truncate data array
-        self.data.truncate(len);
+    pub fn rfind<P>(&self, mut pred: P) -> Option<&T>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.iter().rev().find(|x| pred(x))
     }
 
-    /// Gets an iterator that visits the values in the `RotatedVec` in order.
+    /// Returns the index of the first element in logical order that satisfies
+    /// the predicate, or `None` if there is no such element.
+    ///
+    /// This is an `O(n)` operation.
     ///
     /// # Examples
     ///
     /// ```
     /// use rotated_vec::RotatedVec;
     ///
-    /// let vec: RotatedVec<usize> = vec![1, 2, 3].into();
-    /// let mut iter = vec.iter();
-    /// assert_eq!(iter.next(), Some(&1));
-    /// assert_eq!(iter.next(), Some(&2));
-    /// assert_eq!(iter.next(), Some(&3));
-    /// assert_eq!(iter.next(), None);
+    /// let vec: RotatedVec<_> = vec![1, 2, 3, 4].into();
+    /// assert_eq!(vec.index_of(|&x| x % 2 == 0), Some(1));
+    /// assert_eq!(vec.index_of(|&x| x > 4), None);
     /// ```
-    pub fn iter(&self) -> Iter<T> {
-        Iter {
-            container: self,
-            next_index: 0,
-            next_rev_index: if self.len() == 0 { 0 } else { self.len() - 1 },
-        }
+    pub fn index_of<P>(&self, pred: P) -> Option<usize>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.iter().position(pred)
     }
 
-    /// Gets a mutable iterator that visits the values in the `RotatedVec` in order.
+    /// Returns the index of the last element in logical order that satisfies
+    /// the predicate, or `None` if there is no such element.
+    ///
+    /// This is an `O(n)` operation.
     ///
     /// # Examples
     ///
     /// ```
     /// use rotated_vec::RotatedVec;
     ///
-    /// let mut vec: RotatedVec<usize> = vec![1, 2, 3].into();
-    /// let mut iter = vec.iter_mut();
-    /// let mut current_elem = None;
-    /// current_elem = iter.next();
-    /// assert_eq!(current_elem, Some(&mut 1));
-    /// *current_elem.unwrap() = 2;
-    /// current_elem = iter.next();
-    /// assert_eq!(current_elem, Some(&mut 2));
-    /// *current_elem.unwrap() = 3;
-    /// current_elem = iter.next();
-    /// assert_eq!(current_elem, Some(&mut 3));
-    /// *current_elem.unwrap() = 4;
-    /// assert_eq!(iter.next(), None);
-    /// assert_eq!(vec, vec![2, 3, 4].into());
+    /// let vec: RotatedVec<_> = vec![1, 2, 3, 4].into();
+    /// assert_eq!(vec.rindex_of(|&x| x % 2 == 1), Some(2));
+    /// assert_eq!(vec.rindex_of(|&x| x > 4), None);
     /// ```
-    pub fn iter_mut(&mut self) -> IterMut<T> {
-        let len = self.len();
-        IterMut {
-            container: self,
-            next_index: 0,
-            next_rev_index: len - 1,
-        }
+    pub fn rindex_of<P>(&self, pred: P) -> Option<usize>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.iter().rposition(pred)
     }
 
-    /// Returns the number of elements in the set.
+    /// Returns a reference to the element that gives the minimum value from the
+    /// specified function, or `None` if the vector is empty.
     ///
-    /// This is a constant-time operation.
+    /// If several elements are equally minimum, the first element in logical
+    /// order is returned.
+    ///
+    /// This is an `O(n)` operation.
     ///
     /// # Examples
     ///
     /// ```
     /// use rotated_vec::RotatedVec;
     ///
-    /// let mut vec = RotatedVec::new();
-    /// assert_eq!(vec.len(), 0);
-    /// vec.push(1);
-    /// assert_eq!(vec.len(), 1);
+    /// let vec: RotatedVec<i32> = vec![-3, 0, 1, 5, -10].into();
+    /// assert_eq!(vec.min_by_key(|x| x.abs()), Some(&0));
     /// ```
-    pub fn len(&self) -> usize {
-        self.data.len()
+    pub fn min_by_key<K, F>(&self, mut f: F) -> Option<&T>
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        self.iter().min_by_key(|x| f(x))
     }
 
-    /// Returns `true` if the set contains no elements.
+    /// Returns a reference to the element that gives the maximum value from the
+    /// specified function, or `None` if the vector is empty.
     ///
-    /// This is a constant-time operation.
+    /// If several elements are equally maximum, the last element in logical
+    /// order is returned.
+    ///
+    /// This is an `O(n)` operation.
     ///
     /// # Examples
     ///
     /// ```
     /// use rotated_vec::RotatedVec;
     ///
-    /// let mut vec = RotatedVec::new();
-    /// assert!(vec.is_empty());
-    /// vec.push(1);
-    /// assert!(!vec.is_empty());
+    /// let vec: RotatedVec<i32> = vec![-3, 0, 1, 5, -10].into();
+    /// assert_eq!(vec.max_by_key(|x| x.abs()), Some(&-10));
     /// ```
-    pub fn is_empty(&self) -> bool {
-        self.data.is_empty()
+    pub fn max_by_key<K, F>(&self, mut f: F) -> Option<&T>
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        self.iter().max_by_key(|x| f(x))
     }
 
-    /// Clears the vector, removing all values.
+    /// Sums the elements of the vector in logical order.
     ///
-    /// This is a constant-time operation.
+    /// An empty vector returns the zero value of the type.
+    ///
+    /// This is an `O(n)` operation.
     ///
     /// # Examples
     ///
     /// ```
     /// use rotated_vec::RotatedVec;
     ///
-    /// let mut vec = RotatedVec::new();
-    /// vec.push(1);
-    /// vec.clear();
-    /// assert!(vec.is_empty());
+    /// let vec: RotatedVec<i32> = vec![1, 2, 3, 4].into();
+    /// assert_eq!(vec.sum::<i32>(), 10);
     /// ```
-    pub fn clear(&mut self) {
-        self.data.clear();
-        self.start_indexes.clear();
+    pub fn sum<S>(&self) -> S
+    where
+        S: Sum<T>,
+    {
+        self.iter().copied().sum()
     }
 
-    /// Returns `true` if the `RotatedVec` contains an element equal to the
-    /// given value.
+    /// Multiplies the elements of the vector in logical order.
+    ///
+    /// An empty vector returns the one value of the type.
+    ///
+    /// This is an `O(n)` operation.
     ///
     /// # Examples
     ///
     /// ```
     /// use rotated_vec::RotatedVec;
     ///
-    /// let mut vec = RotatedVec::new();
-    ///
-    /// vec.push(0);
-    /// vec.push(1);
-    ///
-    /// assert_eq!(vec.contains(&1), true);
-    /// assert_eq!(vec.contains(&10), false);
+    /// let vec: RotatedVec<i32> = vec![1, 2, 3, 4].into();
+    /// assert_eq!(vec.product::<i32>(), 24);
     /// ```
-    pub fn contains(&self, x: &T) -> bool
-        where T: PartialEq<T>
+    pub fn product<P>(&self) -> P
+    where
+        P: Product<T>,
     {
-        self.data.contains(x)
+        self.iter().copied().product()
     }
 
-    /// Appends an element to the back of a collection.
+    /// Prepends an element to the front of a collection.
     ///
-    /// This is a constant-time operation.
+    /// This is an `O(√n)` operation.
     ///
     /// # Panics
     ///
@@ -544,18 +3183,18 @@ where
     /// ```
     /// use rotated_vec::RotatedVec;
     ///
-    /// let mut vec: RotatedVec<_> = vec![1, 2].into();
-    /// vec.push(3);
+    /// let mut vec: RotatedVec<_> = vec![2, 3].into();
+    /// vec.push_front(1);
     /// assert_eq!(vec, vec![1, 2, 3].into());
     /// ```
-    pub fn push(&mut self, value: T) {
-        self.insert(self.len(), value);
+    pub fn push_front(&mut self, value: T) {
+        self.insert(0, value);
     }
 
-    /// Removes the last element from a vector and returns it, or [`None`] if it
+    /// Removes the first element from a vector and returns it, or [`None`] if it
     /// is empty.
     ///
-    /// This is a constant-time operation.
+    /// This is an `O(√n)` operation.
     ///
     /// # Examples
     ///
@@ -563,14 +3202,14 @@ where
     /// use rotated_vec::RotatedVec;
     ///
     /// let mut vec: RotatedVec<_> = vec![1, 2, 3].into();
-    /// assert_eq!(vec.pop(), Some(3));
-    /// assert_eq!(vec, vec![1, 2].into());
+    /// assert_eq!(vec.pop_front(), Some(1));
+    /// assert_eq!(vec, vec![2, 3].into());
     /// ```
-    pub fn pop(&mut self) -> Option<T> {
+    pub fn pop_front(&mut self) -> Option<T> {
         if self.is_empty() {
             None
         } else {
-            Some(self.remove(self.len() - 1))
+            Some(self.remove(0))
         }
     }
 
@@ -615,6 +3254,7 @@ where
             // Since we always insert into a partially full subarray in order,
             // there is no need to update the pivot location.
             debug_assert!(self.start_indexes[subarray_idx] == 0);
+            self.record_moves(self.data.len() - insert_idx + 1);
             self.data.insert(insert_idx, element);
             debug_assert!(self.assert_invariants());
             return;
@@ -636,18 +3276,22 @@ where
         // | 12 | 13 | 14 | 15 | 16 | 1 | 2 | 3 | 4 | 5 | 6 | 7 | 8 | 9 | 10 | 11 |
         // ------------------------------------------------------------------------
         //
-        if end_offset < pivot_offset && insert_offset >= pivot_offset {
+        let subarray_move_count = if end_offset < pivot_offset && insert_offset >= pivot_offset {
             subarray.copy_within(pivot_offset..insert_offset, end_offset);
             subarray[insert_offset - 1] = element;
             self.start_indexes[subarray_idx] = end_offset;
+            insert_offset - pivot_offset
         } else {
             subarray.copy_within(insert_offset..end_offset, insert_offset + 1);
             subarray[insert_offset] = element;
-        }
+            end_offset - insert_offset
+        };
+        self.record_moves(subarray_move_count + 1);
         debug_assert!(self.assert_invariants());
         let max_subarray_idx = self.start_indexes.len() - 1;
         let next_subarray_idx = subarray_idx + 1;
         let last_subarray_full = self.is_last_subarray_full();
+        let mut exchange_count = 0;
         // now loop over all remaining subarrays, setting the first (pivot) of each to the last of its predecessor
         for (i, pivot_offset_ref) in self.start_indexes[next_subarray_idx..].iter_mut().enumerate() {
             let cur_subarray_idx = next_subarray_idx + i;
@@ -665,13 +3309,17 @@ where
             self.data[end_idx] = prev_end_elem;
             *pivot_offset_ref = end_offset;
             prev_end_elem = next_end_elem;
+            exchange_count += 1;
         }
+        self.record_moves(exchange_count);
         // if the last subarray was full, append current last element to a new subarray, otherwise insert last element in rotated order
         if last_subarray_full {
+            self.record_moves(1);
             self.data.push(prev_end_elem);
             self.start_indexes.push(0);
         } else {
             let max_subarray_offset = Self::get_array_idx_from_subarray_idx(max_subarray_idx);
+            self.record_moves(self.data.len() - max_subarray_offset + 1);
             // since `prev_end_elem` is guaranteed to be <= the pivot value, we always insert it at the pivot location
             self.data.insert(max_subarray_offset, prev_end_elem);
         }
@@ -679,126 +3327,258 @@ where
         debug_assert!(self.assert_invariants());
     }
 
-    /// Removes and returns the element at position `index` within the vector.
+    /// Returns a cursor for inserting consecutive elements starting at position
+    /// `index` within the vector.
     ///
-    /// This is an `O(√n)` operation.
+    /// The inserted elements are moved into the vector when the cursor is
+    /// dropped, which takes `O(min(n, k√n))` time for `k` elements.
     ///
     /// # Panics
     ///
-    /// Panics if `index` is out of bounds.
+    /// Panics if `index > len`.
     ///
     /// # Examples
     ///
     /// ```
     /// use rotated_vec::RotatedVec;
     ///
-    /// let mut vec: RotatedVec<_> = vec![1, 2, 3].into();
-    /// assert_eq!(vec.remove(1), 2);
-    /// assert_eq!(vec, vec![1, 3].into());
+    /// let mut vec: RotatedVec<_> = vec![1, 5].into();
+    /// {
+    ///     let mut cursor = vec.insert_cursor(1);
+    ///     for i in 2..5 {
+    ///         cursor.insert_next(i);
+    ///     }
+    /// }
+    /// assert_eq!(vec, vec![1, 2, 3, 4, 5].into());
     /// ```
-    pub fn remove(&mut self, index: usize) -> T {
-        assert!(index < self.len());
-        let old_len = self.len();
-        let mut remove_idx = self.get_real_index(index);
-        let element = self.data[remove_idx];
-        let max_subarray_idx = self.start_indexes.len() - 1;
-        let max_subarray_offset = Self::get_array_idx_from_subarray_idx(max_subarray_idx);
-        // find subarray containing the element to remove
-        let subarray_idx = Self::get_subarray_idx_from_array_idx(remove_idx);
-        debug_assert!(subarray_idx <= max_subarray_idx);
-        let subarray_offset = Self::get_array_idx_from_subarray_idx(subarray_idx);
-        // if we're not removing an element in the last subarray, then we end up deleting its first element,
-        // which is always at the first offset since it's in order
-        let mut max_subarray_remove_idx = if subarray_idx == max_subarray_idx {
-            remove_idx
-        } else {
-            max_subarray_offset
-        };
-        // if the last subarray was rotated, un-rotate it to maintain insert invariant
-        if self.is_last_subarray_full() {
-            let last_start_offset = self.start_indexes[max_subarray_idx];
-            // rotate left by the start offset
-            self.data[max_subarray_offset..].rotate_left(last_start_offset);
-            self.start_indexes[max_subarray_idx] = 0;
-            // the remove index might change after un-rotating the last subarray
-            if subarray_idx == max_subarray_idx {
-                remove_idx = self.get_real_index(index);
-                max_subarray_remove_idx = remove_idx;
+    pub fn insert_cursor(&mut self, index: usize) -> InsertCursor<'_, T> {
+        assert!(index <= self.len());
+        InsertCursor {
+            container: self,
+            index,
+            pending: Vec::new(),
+        }
+    }
+
+    /// Inserts an element into a sorted vector, keeping it sorted, and returns
+    /// the index at which it was inserted.
+    ///
+    /// If the vector already contains elements equal to `element`, it is
+    /// inserted after all of them. If the vector is not sorted, the insertion
+    /// point is unspecified.
+    ///
+    /// This is an `O(√n)` operation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::RotatedVec;
+    ///
+    /// let mut vec: RotatedVec<_> = vec![1, 2, 4, 5].into();
+    /// assert_eq!(vec.insert_sorted(3), 2);
+    /// assert_eq!(vec, vec![1, 2, 3, 4, 5].into());
+    /// ```
+    pub fn insert_sorted(&mut self, element: T) -> usize
+    where
+        T: Ord,
+    {
+        let index = self.partition_point(|x| *x <= element);
+        self.insert(index, element);
+        index
+    }
+
+    /// Inserts an element into a sorted vector, keeping it sorted, unless an
+    /// equal element is already present.
+    ///
+    /// Returns `Ok(index)` with the index at which the element was inserted,
+    /// or `Err(index)` with the index of an existing equal element, in which
+    /// case the vector is unchanged. This gives the vector set semantics. If
+    /// the vector is not sorted, the result is unspecified.
+    ///
+    /// This is an `O(√n)` operation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::RotatedVec;
+    ///
+    /// let mut vec: RotatedVec<_> = vec![1, 2, 4, 5].into();
+    /// assert_eq!(vec.insert_unique(3), Ok(2));
+    /// assert_eq!(vec.insert_unique(4), Err(3));
+    /// assert_eq!(vec, vec![1, 2, 3, 4, 5].into());
+    /// ```
+    pub fn insert_unique(&mut self, element: T) -> Result<usize, usize>
+    where
+        T: Ord,
+    {
+        match self.binary_search(&element) {
+            Ok(index) => Err(index),
+            Err(index) => {
+                self.insert(index, element);
+                Ok(index)
             }
         }
-        // if insertion point is not in last subarray, perform a "hard exchange"
-        if subarray_idx < max_subarray_idx {
-            // From now on, we can assume that the subarray we're removing from is full.
-            let next_subarray_offset = Self::get_array_idx_from_subarray_idx(subarray_idx + 1);
-            let subarray = &mut self.data[subarray_offset..next_subarray_offset];
-            let pivot_offset = self.start_indexes[subarray_idx];
-            let remove_offset = remove_idx - subarray_offset;
-            let end_offset = if pivot_offset == 0 {
-                subarray.len() - 1
-            } else {
-                pivot_offset - 1
-            };
-            // this logic is best understood with a diagram of a rotated array, e.g.:
-            //
-            // ------------------------------------------------------------------------
-            // | 12 | 13 | 14 | 15 | 16 | 1 | 2 | 3 | 4 | 5 | 6 | 7 | 8 | 9 | 10 | 11 |
-            // ------------------------------------------------------------------------
-            //
-            let mut prev_end_offset = if end_offset < pivot_offset && remove_offset >= pivot_offset
-            {
-                subarray.copy_within(pivot_offset..remove_offset, pivot_offset + 1);
-                let new_pivot_offset = if pivot_offset == subarray.len() - 1 {
-                    0
-                } else {
-                    pivot_offset + 1
-                };
-                self.start_indexes[subarray_idx] = new_pivot_offset;
-                pivot_offset
+    }
+
+    /// Inserts all elements of an iterator into a sorted vector, keeping it
+    /// sorted.
+    ///
+    /// The items need not be sorted themselves. As with [`insert_sorted`],
+    /// new elements are placed after any existing elements equal to them. If
+    /// the vector is not sorted, the resulting order is unspecified.
+    ///
+    /// Rather than inserting the items one at a time, this sorts them, then
+    /// merges them with the un-rotated contents of the vector in a single
+    /// pass. So this is an `O(n + k log k)` operation, where `k` is the number
+    /// of items.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::RotatedVec;
+    ///
+    /// let mut vec: RotatedVec<_> = vec![1, 3, 5].into();
+    /// vec.insert_all_sorted(vec![6, 2, 4, 0]);
+    /// assert_eq!(vec, vec![0, 1, 2, 3, 4, 5, 6].into());
+    /// ```
+    ///
+    /// [`insert_sorted`]: #method.insert_sorted
+    pub fn insert_all_sorted<I: IntoIterator<Item = T>>(&mut self, items: I)
+    where
+        T: Ord,
+    {
+        let mut items: Vec<T> = items.into_iter().collect();
+        if items.is_empty() {
+            return;
+        }
+        items.sort();
+        // operate directly on the data array in logical order
+        self.normalize();
+        let mut old_idx = self.data.len();
+        let mut new_idx = items.len();
+        self.data.extend_from_slice(&items);
+        // merge from the back, so each slot is overwritten only after it has been read
+        for merged_idx in (0..self.data.len()).rev() {
+            if new_idx == 0 {
+                break;
+            }
+            if old_idx > 0 && self.data[old_idx - 1] > items[new_idx - 1] {
+                self.data[merged_idx] = self.data[old_idx - 1];
+                old_idx -= 1;
             } else {
-                subarray.copy_within(remove_offset + 1..=end_offset, remove_offset);
-                end_offset
-            };
-            let next_subarray_idx = min(max_subarray_idx, subarray_idx + 1);
-            // now perform an "easy exchange" in all remaining subarrays except the last,
-            // setting the last element of each to the first element of its successor.
-            for (i, pivot_offset_ref) in self.start_indexes[next_subarray_idx..max_subarray_idx]
-                .iter_mut()
-                .enumerate()
-            {
-                let cur_subarray_idx = next_subarray_idx + i;
-                let cur_subarray_offset = Self::get_array_idx_from_subarray_idx(cur_subarray_idx);
-                let prev_end_idx =
-                    prev_end_offset + Self::get_array_idx_from_subarray_idx(cur_subarray_idx - 1);
-                self.data[prev_end_idx] = self.data[cur_subarray_offset + *pivot_offset_ref];
-                prev_end_offset = *pivot_offset_ref;
-                let new_start_offset = if *pivot_offset_ref == cur_subarray_idx {
-                    0
-                } else {
-                    *pivot_offset_ref + 1
-                };
-                *pivot_offset_ref = new_start_offset;
+                self.data[merged_idx] = items[new_idx - 1];
+                new_idx -= 1;
             }
-            // now we fix up the last subarray. if it was initially full, we need to un-rotate it to maintain the insert invariant.
-            // if the removed element is in the last subarray, we just un-rotate and remove() on the vec, updating auxiliary arrays.
-            // otherwise, we copy the first element to the last position of the previous subarray, then remove it and fix up
-            // auxiliary arrays.
-            let prev_end_idx =
-                prev_end_offset + Self::get_array_idx_from_subarray_idx(max_subarray_idx - 1);
-            // since the last subarray is always in order, its first element is always on the first offset
-            self.data[prev_end_idx] = self.data[max_subarray_offset];
         }
-        self.data.remove(max_subarray_remove_idx);
-        // if last subarray is now empty, trim start_indexes
-        if max_subarray_offset == self.data.len() {
-            self.start_indexes.pop();
+        self.start_indexes.clear();
+        self.init();
+        debug_assert!(self.assert_invariants());
+    }
+
+    /// Inserts an element at position `index` within the vector, or returns
+    /// the element back if `index > len`.
+    ///
+    /// This is an `O(√n)` operation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::RotatedVec;
+    ///
+    /// let mut vec: RotatedVec<_> = vec![1, 2, 3].into();
+    /// assert_eq!(vec.checked_insert(1, 4), Ok(()));
+    /// assert_eq!(vec.checked_insert(5, 5), Err(5));
+    /// assert_eq!(vec, vec![1, 4, 2, 3].into());
+    /// ```
+    pub fn checked_insert(&mut self, index: usize, element: T) -> Result<(), T> {
+        if index > self.len() {
+            Err(element)
+        } else {
+            self.insert(index, element);
+            Ok(())
+        }
+    }
+
+    /// Removes and returns the element at position `index` within the vector,
+    /// or returns `None` if `index` is out of bounds.
+    ///
+    /// This is an `O(√n)` operation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::RotatedVec;
+    ///
+    /// let mut vec: RotatedVec<_> = vec![1, 2, 3].into();
+    /// assert_eq!(vec.checked_remove(1), Some(2));
+    /// assert_eq!(vec.checked_remove(2), None);
+    /// assert_eq!(vec, vec![1, 3].into());
+    /// ```
+    pub fn checked_remove(&mut self, index: usize) -> Option<T> {
+        if index >= self.len() {
+            None
+        } else {
+            Some(self.remove(index))
+        }
+    }
+
+    /// Removes the elements at all the given positions within the vector,
+    /// shifting the remaining elements to fill the gaps.
+    ///
+    /// The indexes refer to positions before any removal, and must be sorted
+    /// in strictly increasing order.
+    ///
+    /// This is an `O(n)` operation, which is cheaper than calling [`remove`]
+    /// repeatedly once more than `√n` elements are removed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `indices` is not strictly increasing or any index is out of
+    /// bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::RotatedVec;
+    ///
+    /// let mut vec: RotatedVec<_> = vec![1, 2, 3, 4, 5, 6].into();
+    /// vec.remove_indices(&[0, 2, 3]);
+    /// assert_eq!(vec, vec![2, 5, 6].into());
+    /// ```
+    ///
+    /// [`remove`]: #method.remove
+    pub fn remove_indices(&mut self, indices: &[usize]) {
+        assert!(indices.windows(2).all(|w| w[0] < w[1]));
+        if let Some(&last) = indices.last() {
+            assert!(last < self.len());
+        } else {
+            return;
         }
-        debug_assert!(self.len() == old_len - 1);
+        // operate directly on the data array in logical order
+        self.normalize();
+        let mut indices = indices.iter().peekable();
+        let mut index = 0;
+        self.data.retain(|_| {
+            let keep = indices.peek() != Some(&&index);
+            if !keep {
+                indices.next();
+            }
+            index += 1;
+            keep
+        });
+        self.start_indexes.clear();
+        self.init();
         debug_assert!(self.assert_invariants());
-        element
     }
 
     /// Moves all the elements of `other` into `self`, leaving `other` empty.
     ///
+    /// This moves whichever side is cheaper: normally the elements of `other`
+    /// are copied after those of `self` in `O(m)` time, but if `self` is much
+    /// shorter, its elements are instead inserted at the front of `other` in
+    /// `O(n√m)` time and the two vectors are swapped. Either way, the
+    /// elements of `self` precede those of `other` in the result.
+    ///
     /// # Panics
     ///
     /// Panics if the number of elements in the array overflows a `usize`.
@@ -819,6 +3599,27 @@ where
         if !self.is_last_subarray_full() {
             self.unrotate_last_subarray();
         }
+        if other.is_empty() {
+            return;
+        }
+        // if `self` is short enough, inserting its elements at the front of `other`
+        // moves fewer elements than copying all of `other`
+        if self.len() * other.subarray_count() < other.len() {
+            for (i, &x) in self.iter().enumerate() {
+                other.insert(i, x);
+            }
+            #[cfg(feature = "metrics")]
+            {
+                other.move_count += mem::take(&mut self.move_count);
+            }
+            mem::swap(self, other);
+            other.clear();
+            debug_assert!(self.assert_invariants());
+            return;
+        }
+        self.record_moves(other.len());
+        // un-rotate `other` so its data array is in logical order
+        other.normalize();
         // append data directly to backing array
         self.data.append(&mut other.data);
         // fix up start indexes
@@ -826,6 +3627,157 @@ where
         self.start_indexes.resize(last_subarray_idx + 1, 0);
         // clear all data in `other`
         other.clear();
+        debug_assert!(self.assert_invariants());
+    }
+
+    /// Copies all the elements of `other` onto the end of `self`, in order,
+    /// leaving `other` unchanged.
+    ///
+    /// Unlike [`append`], this doesn't consume the elements of `other`, so the
+    /// same vector can be copied into several others.
+    ///
+    /// This is an `O(m)` operation, where `m` is the length of `other`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of elements in the array overflows a `usize`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::RotatedVec;
+    ///
+    /// let mut vec: RotatedVec<_> = vec![1, 2, 3].into();
+    /// let vec2: RotatedVec<_> = vec![4, 5, 6].into();
+    /// vec.extend_from_rotated(&vec2);
+    /// assert_eq!(vec, vec![1, 2, 3, 4, 5, 6].into());
+    /// assert_eq!(vec2, vec![4, 5, 6].into());
+    /// ```
+    ///
+    /// [`append`]: #method.append
+    pub fn extend_from_rotated(&mut self, other: &RotatedVec<T>) {
+        self.reserve(other.len());
+        self.extend(other.iter().copied());
+    }
+
+    /// Removes consecutive repeated elements in the vector, returning each
+    /// remaining element paired with the length of the run it replaced.
+    ///
+    /// This is a run-length encoding of the original contents. If the vector
+    /// is sorted, this removes all duplicates.
+    ///
+    /// This is an `O(n)` operation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::RotatedVec;
+    ///
+    /// let mut vec: RotatedVec<_> = vec![1, 1, 2, 3, 3, 3, 1].into();
+    /// assert_eq!(vec.dedup_counted(), vec![(1, 2), (2, 1), (3, 3), (1, 1)]);
+    /// assert_eq!(vec, vec![1, 2, 3, 1].into());
+    /// ```
+    pub fn dedup_counted(&mut self) -> Vec<(T, usize)>
+    where
+        T: PartialEq,
+    {
+        let mut runs: Vec<(T, usize)> = Vec::new();
+        for run in self.contiguous_runs() {
+            for &x in run {
+                match runs.last_mut() {
+                    Some((y, count)) if *y == x => *count += 1,
+                    _ => runs.push((x, 1)),
+                }
+            }
+        }
+        // if there were no repeats, leave the layout untouched
+        if runs.len() == self.len() {
+            return runs;
+        }
+        self.data.clear();
+        self.data.extend(runs.iter().map(|&(x, _)| x));
+        self.start_indexes.clear();
+        self.init();
+        runs
+    }
+
+    /// Returns an iterator over the contents of the vector as a sequence of
+    /// contiguous slices, in order.
+    ///
+    /// Concatenating the yielded slices reconstructs the logical order of the
+    /// vector. Each subarray of the internal layout yields one slice if it is
+    /// in order and two if it is rotated, so this yields `O(√n)` slices.
+    /// This is useful for bulk copies or vectorized processing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::RotatedVec;
+    ///
+    /// let mut vec: RotatedVec<_> = vec![1, 2, 3, 4, 5, 6].into();
+    /// vec.insert(0, 0);
+    /// let flattened: Vec<_> = vec.contiguous_runs().flatten().cloned().collect();
+    /// assert_eq!(flattened, vec![0, 1, 2, 3, 4, 5, 6]);
+    /// ```
+    pub fn contiguous_runs(&self) -> impl Iterator<Item = &[T]> + '_ {
+        self.runs_in_range(0, self.len())
+    }
+
+    /// Returns an independent copy of the vector with a normalized internal
+    /// layout.
+    ///
+    /// Unlike `clone()`, which copies the rotated layout verbatim, this copies
+    /// the elements in logical order, so no subarray of the snapshot is
+    /// rotated. Two vectors with equal contents therefore have identical
+    /// snapshots, down to their raw parts, which is useful for deterministic
+    /// serialization or comparison.
+    ///
+    /// This is an `O(n)` operation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::RotatedVec;
+    ///
+    /// let mut vec: RotatedVec<_> = vec![1, 2, 3].into();
+    /// vec.insert(0, 0);
+    /// let snapshot = vec.snapshot();
+    /// assert_eq!(snapshot, vec);
+    /// assert_eq!(snapshot.as_logical_slice(), Some(&[0, 1, 2, 3][..]));
+    /// ```
+    pub fn snapshot(&self) -> RotatedVec<T> {
+        let mut data = Vec::new();
+        self.clone_into_vec(&mut data);
+        RotatedVec::from(data)
+    }
+
+    /// Clears `target` and clones the elements of the vector into it, in order.
+    ///
+    /// This reuses the existing capacity of `target`, so repeatedly
+    /// snapshotting into the same `Vec` only allocates when it must grow.
+    ///
+    /// This is an `O(n)` operation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::RotatedVec;
+    ///
+    /// let mut vec: RotatedVec<_> = vec![1, 2, 3].into();
+    /// vec.insert(0, 0);
+    /// let mut target = vec![9; 10];
+    /// vec.clone_into_vec(&mut target);
+    /// assert_eq!(target, vec![0, 1, 2, 3]);
+    /// ```
+    pub fn clone_into_vec(&self, target: &mut Vec<T>)
+    where
+        T: Clone,
+    {
+        target.clear();
+        target.reserve(self.len());
+        for run in self.contiguous_runs() {
+            target.extend_from_slice(run);
+        }
     }
 
     /// Sorts the vector.
@@ -906,96 +3858,210 @@ where
         }
     }
 
-    // this returns the index in the backing array of the given logical index
-    fn get_real_index(&self, index: usize) -> usize {
-        debug_assert!(index < self.data.len());
-        let subarray_idx = Self::get_subarray_idx_from_array_idx(index);
-        let subarray_start_idx = Self::get_array_idx_from_subarray_idx(subarray_idx);
-        let subarray_len = if subarray_idx == self.start_indexes.len() - 1 {
-            self.data.len() - subarray_start_idx
-        } else {
-            subarray_idx + 1
-        };
-        debug_assert!(index >= subarray_start_idx);
-        let idx_offset = index - subarray_start_idx;
-        let pivot_offset = self.start_indexes[subarray_idx];
-        let rotated_offset = (pivot_offset + idx_offset) % subarray_len;
-        debug_assert!(rotated_offset < subarray_len);
-        let real_idx = subarray_start_idx + rotated_offset;
-        real_idx
+    /// Shuffles the vector in place, using the given random number generator.
+    ///
+    /// This is an `O(n)` operation.
+    ///
+    /// This method is only available when the `rand` feature is enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "rand")] {
+    /// use rand::SeedableRng;
+    /// use rand::rngs::StdRng;
+    /// use rotated_vec::RotatedVec;
+    ///
+    /// let mut vec: RotatedVec<_> = vec![1, 2, 3, 4, 5].into();
+    /// vec.shuffle(&mut StdRng::seed_from_u64(42));
+    /// vec.sort();
+    /// assert_eq!(vec, vec![1, 2, 3, 4, 5].into());
+    /// # }
+    /// ```
+    #[cfg(feature = "rand")]
+    pub fn shuffle<R>(&mut self, rng: &mut R)
+    where
+        R: rand::Rng + ?Sized,
+    {
+        use rand::seq::SliceRandom;
+        // shuffle in logical order, so the result only depends on the logical contents
+        let mut vec: Vec<T> = mem::take(self).into();
+        vec.shuffle(rng);
+        *self = vec.into();
     }
 
-    fn integer_sum(n: usize)    -> usize {
-        // I learned this from a 10-year-old named Gauss
-        (n * (n + 1)) / 2
+    /// Rotates the given subarray of the internal layout so that its first
+    /// logical element is stored at offset `pivot_offset`, without changing
+    /// the logical contents of the vector.
+    ///
+    /// This is intended for fuzzers and benchmarks that need to construct
+    /// specific internal layouts deterministically, and is only available
+    /// when the `testing` feature is enabled.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `subarray_idx` is out of bounds, if `pivot_offset > subarray_idx`,
+    /// or if `pivot_offset` is nonzero and the subarray is the last one and is
+    /// not full.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "testing")] {
+    /// use rotated_vec::RotatedVec;
+    ///
+    /// let mut vec: RotatedVec<_> = vec![1, 2, 3, 4, 5, 6].into();
+    /// vec.force_pivot(2, 1);
+    /// assert_eq!(vec, vec![1, 2, 3, 4, 5, 6].into());
+    /// assert_eq!(vec.as_logical_slice(), None);
+    /// # }
+    /// ```
+    #[cfg(feature = "testing")]
+    pub fn force_pivot(&mut self, subarray_idx: usize, pivot_offset: usize) {
+        assert!(subarray_idx < self.start_indexes.len());
+        assert!(pivot_offset <= subarray_idx);
+        let subarray_start_idx = Self::get_array_idx_from_subarray_idx(subarray_idx);
+        let subarray_end_idx = min(subarray_start_idx + subarray_idx + 1, self.data.len());
+        // a partially full last subarray must stay in order to maintain the insert invariant
+        assert!(pivot_offset == 0 || subarray_end_idx - subarray_start_idx == subarray_idx + 1);
+        let subarray = &mut self.data[subarray_start_idx..subarray_end_idx];
+        subarray.rotate_left(self.start_indexes[subarray_idx]);
+        subarray.rotate_right(pivot_offset);
+        self.start_indexes[subarray_idx] = pivot_offset;
+        assert!(self.assert_invariants());
     }
 
-    fn integer_sum_inverse(n: usize) -> usize {
-        // y = (x * (x + 1)) / 2
-        // x = (sqrt(8 * y + 1) - 1) / 2
-        ((f64::from((n * 8 + 1) as u32).sqrt() as usize) - 1) / 2
-    }
+}
 
-    fn get_subarray_idx_from_array_idx(idx: usize) -> usize {
-        if idx == 0 {
-            0
-        } else {
-            Self::integer_sum_inverse(idx)
-        }
+impl RotatedVec<bool> {
+    /// Returns the number of `true` elements in the vector.
+    ///
+    /// Since counting doesn't depend on order, this scans the backing storage
+    /// directly.
+    ///
+    /// This is an `O(n)` operation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::RotatedVec;
+    ///
+    /// let vec: RotatedVec<_> = vec![true, false, true, true].into();
+    /// assert_eq!(vec.count_true(), 3);
+    /// ```
+    pub fn count_true(&self) -> usize {
+        self.data.iter().filter(|&&b| b).count()
     }
 
-    fn get_array_idx_from_subarray_idx(idx: usize) -> usize {
-        if idx == 0 {
-            0
-        } else {
-            Self::integer_sum(idx)
-        }
+    /// Returns the number of `false` elements in the vector.
+    ///
+    /// Since counting doesn't depend on order, this scans the backing storage
+    /// directly.
+    ///
+    /// This is an `O(n)` operation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::RotatedVec;
+    ///
+    /// let vec: RotatedVec<_> = vec![true, false, true, true].into();
+    /// assert_eq!(vec.count_false(), 1);
+    /// ```
+    pub fn count_false(&self) -> usize {
+        self.len() - self.count_true()
     }
+}
 
-    fn is_last_subarray_full(&self) -> bool {
-        self.data.len() == Self::get_array_idx_from_subarray_idx(self.start_indexes.len())
+impl RotatedVec<f64> {
+    /// Returns `true` if both vectors have the same length and each pair of
+    /// elements in logical order differs by at most `epsilon`.
+    ///
+    /// This is an `O(n)` operation, but it returns immediately if the lengths
+    /// differ.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::RotatedVec;
+    ///
+    /// let vec: RotatedVec<f64> = vec![0.1 + 0.2, 1.0].into();
+    /// let other: RotatedVec<f64> = vec![0.3, 1.0].into();
+    /// assert_ne!(vec, other);
+    /// assert!(vec.approx_eq(&other, 1e-9));
+    /// ```
+    pub fn approx_eq(&self, other: &Self, epsilon: f64) -> bool {
+        self.len() == other.len() && self.iter().zip(other.iter()).all(|(x, y)| (x - y).abs() <= epsilon)
     }
+}
 
-    fn unrotate_last_subarray(&mut self) {
-        let last_subarray_idx = Self::get_subarray_idx_from_array_idx(self.len() - 1);
-        let last_subarray_start_idx = Self::get_array_idx_from_subarray_idx(last_subarray_idx);
-        let last_subarray_len = if last_subarray_idx == self.start_indexes.len() - 1 {
-            self.len() - last_subarray_start_idx
-        } else {
-            last_subarray_idx + 1
-        };
-        let last_subarray_end_idx = last_subarray_start_idx + last_subarray_len;
-        let last_subarray = &mut self.data[last_subarray_start_idx..last_subarray_end_idx];
-        // un-rotate subarray in-place
-        let pivot_offset = self.start_indexes[last_subarray_idx];
-        last_subarray.rotate_left(pivot_offset);
-        self.start_indexes[last_subarray_idx] = 0;
+impl<T> Clone for RotatedVec<T>
+where
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        RotatedVec {
+            data: self.data.clone(),
+            start_indexes: self.start_indexes.clone(),
+            #[cfg(feature = "metrics")]
+            move_count: 0,
+        }
     }
 
-    #[inline(always)]
-    fn assert_invariants(&self) -> bool {
-        // assert offset array has proper length
-        let expected_start_indexes_len = if self.is_empty() {
-            0
-        } else {
-            Self::get_subarray_idx_from_array_idx(self.len() - 1) + 1
-        };
-        assert_eq!(self.start_indexes.len(), expected_start_indexes_len);
-        // assert index of each subarray's first element lies within the subarray
-        assert!(self
-            .start_indexes
-            .iter()
-            .enumerate()
-            .all(|(idx, &offset)| offset <= idx));
-        true
+    /// Overwrites `self` with a copy of `source`, reusing the existing
+    /// allocations of `self` where possible.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::RotatedVec;
+    ///
+    /// let source: RotatedVec<_> = vec![1, 2, 3].into();
+    /// let mut vec = RotatedVec::with_capacity(10);
+    /// vec.clone_from(&source);
+    /// assert_eq!(vec, source);
+    /// assert_eq!(vec.capacity(), 10);
+    /// ```
+    fn clone_from(&mut self, source: &Self) {
+        self.data.clone_from(&source.data);
+        self.start_indexes.clone_from(&source.start_indexes);
     }
+}
 
-    // given data array, initialize offset array
-    fn init(&mut self) {
-        debug_assert!(self.start_indexes.is_empty());
-        if !self.data.is_empty() {
-            let last_subarray_idx = Self::get_subarray_idx_from_array_idx(self.data.len() - 1);
-            self.start_indexes = vec![0; last_subarray_idx + 1];
+impl<T> Debug for RotatedVec<T>
+where
+    T: Debug,
+{
+    /// Formats the elements in logical order, like `Vec`.
+    ///
+    /// The alternate flag (`{:#?}`) instead shows the internal rotated layout.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::RotatedVec;
+    ///
+    /// let vec: RotatedVec<_> = vec![1, 2, 3].into();
+    /// assert_eq!(format!("{:?}", vec), "[1, 2, 3]");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            f.debug_struct("RotatedVec")
+                .field("data", &self.data)
+                .field("start_indexes", &self.start_indexes)
+                .finish()
+        } else {
+            // walk each subarray from its pivot, since `iter()` requires stricter bounds
+            let mut list = f.debug_list();
+            let mut subarray_start_idx = 0;
+            for (i, &pivot_offset) in self.start_indexes.iter().enumerate() {
+                let subarray_end_idx = min(subarray_start_idx + i + 1, self.data.len());
+                let subarray = &self.data[subarray_start_idx..subarray_end_idx];
+                list.entries(&subarray[pivot_offset..]);
+                list.entries(&subarray[..pivot_offset]);
+                subarray_start_idx = subarray_end_idx;
+            }
+            list.finish()
         }
     }
 }
@@ -1017,6 +4083,31 @@ where
     }
 }
 
+// only this direction is implemented: a second `PartialEq` impl on `RotatedVec`
+// would break type inference for the common `vec == other.into()` comparison
+impl<T> PartialEq<RotatedVec<T>> for VecDeque<T>
+where
+    T: Copy + Default + Debug + PartialEq,
+{
+    /// Compares a `VecDeque` with a `RotatedVec` element-by-element in
+    /// logical order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::VecDeque;
+    /// use rotated_vec::RotatedVec;
+    ///
+    /// let mut vec: RotatedVec<_> = vec![2, 3].into();
+    /// vec.push_front(1);
+    /// let deque: VecDeque<_> = vec![1, 2, 3].into();
+    /// assert_eq!(deque, vec);
+    /// ```
+    fn eq(&self, other: &RotatedVec<T>) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
 impl<T> Eq for RotatedVec<T>
 where
     T: Copy + Default + Debug + PartialEq
@@ -1027,7 +4118,10 @@ where
     T: Copy + Default + Debug + PartialOrd
 {
     fn partial_cmp(&self, other: &RotatedVec<T>) -> Option<Ordering> {
-        self.iter().partial_cmp(other.iter())
+        // walk contiguous runs rather than mapping each logical index
+        self.contiguous_runs()
+            .flatten()
+            .partial_cmp(other.contiguous_runs().flatten())
     }
 }
 
@@ -1036,7 +4130,10 @@ where
     T: Copy + Default + Debug + Ord
 {
     fn cmp(&self, other: &RotatedVec<T>) -> Ordering {
-        self.iter().cmp(other.iter())
+        // walk contiguous runs rather than mapping each logical index
+        self.contiguous_runs()
+            .flatten()
+            .cmp(other.contiguous_runs().flatten())
     }
 }
 
@@ -1085,11 +4182,98 @@ where
         if !self.is_last_subarray_full() {
             self.unrotate_last_subarray();
         }
-        // append data directly to backing array
+        // append data directly to backing array. this leaves the pivots of all
+        // previously full subarrays intact, since their elements don't move.
         self.data.extend(iter);
+        if self.data.is_empty() {
+            return;
+        }
         // fix up start indexes
         let last_subarray_idx = Self::get_subarray_idx_from_array_idx(self.data.len() - 1);
         self.start_indexes.resize(last_subarray_idx + 1, 0);
+        debug_assert!(self.assert_invariants());
+    }
+
+    #[cfg(feature = "nightly")]
+    fn extend_one(&mut self, item: T) {
+        self.push(item);
+    }
+
+    #[cfg(feature = "nightly")]
+    fn extend_reserve(&mut self, additional: usize) {
+        self.data.reserve(additional);
+        let additional_subarrays = self.additional_subarrays(additional);
+        self.start_indexes.reserve(additional_subarrays);
+    }
+}
+
+impl<'a, T> Extend<&'a T> for RotatedVec<T>
+where
+    T: Copy + Default + Debug + 'a,
+{
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = &'a T>,
+    {
+        self.extend(iter.into_iter().copied());
+    }
+
+    #[cfg(feature = "nightly")]
+    fn extend_one(&mut self, &item: &'a T) {
+        self.push(item);
+    }
+
+    #[cfg(feature = "nightly")]
+    fn extend_reserve(&mut self, additional: usize) {
+        <Self as Extend<T>>::extend_reserve(self, additional);
+    }
+}
+
+#[cfg(feature = "std")]
+impl io::Write for RotatedVec<u8> {
+    /// Appends the bytes in `buf` to the end of the vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::Write;
+    /// use rotated_vec::RotatedVec;
+    ///
+    /// let mut vec = RotatedVec::new();
+    /// write!(vec, "{}-{}", 1, 2).unwrap();
+    /// assert_eq!(vec, b"1-2".to_vec().into());
+    /// ```
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.extend(buf.iter().cloned());
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl fmt::Display for RotatedVec<char> {
+    /// Writes the characters of the vector in order, so that a `RotatedVec<char>`
+    /// can serve as an editable text buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::RotatedVec;
+    ///
+    /// let mut vec: RotatedVec<char> = "hlo".chars().collect();
+    /// vec.insert(1, 'e');
+    /// vec.insert(2, 'l');
+    /// assert_eq!(vec.to_string(), "hello");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for run in self.contiguous_runs() {
+            for &c in run {
+                fmt::Write::write_char(f, c)?;
+            }
+        }
+        Ok(())
     }
 }
 
@@ -1100,7 +4284,7 @@ where
     type Item = &'a T;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.len() == 0 || self.next_index > self.next_rev_index {
+        if self.next_index == self.end_index {
             None
         } else {
             let current = self.container.get(self.next_index);
@@ -1111,45 +4295,65 @@ where
     }
 
     fn nth(&mut self, n: usize) -> Option<Self::Item> {
-        self.next_index = min(self.next_index + n, self.len());
-        let ret = if self.len() == 0 || self.next_index > self.next_rev_index {
+        // never advance past the back cursor, so a later `nth_back()` can't revisit elements
+        if n >= self.end_index - self.next_index {
+            self.next_index = self.end_index;
+            debug_assert!(self.assert_invariants());
             None
         } else {
-            let nth = self.container.get(self.next_index);
-            self.next_index += 1;
-            nth
-        };
-        debug_assert!(self.assert_invariants());
-        ret
+            self.next_index += n;
+            self.next()
+        }
     }
 
     fn count(self) -> usize {
-        self.len() - self.next_index
+        self.len()
     }
 
-    fn last(self) -> Option<Self::Item> {
-        if self.len() == 0 {
-            None
-        } else {
-            self.container.get(self.len() - 1)
-        }
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
     }
 
-    fn max(self) -> Option<Self::Item> {
-        if self.len() == 0 {
-            None
-        } else {
-            self.container.get(self.len() - 1)
-        }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining_count = self.len();
+        (remaining_count, Some(remaining_count))
     }
 
-    fn min(self) -> Option<Self::Item> {
-        self.container.get(0)
+    fn fold<B, F>(self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, Self::Item) -> B,
+    {
+        // walk whole contiguous runs rather than mapping each logical index separately
+        self.container
+            .runs_in_range(self.next_index, self.end_index)
+            .fold(init, |acc, run| run.iter().fold(acc, &mut f))
     }
 
-    fn size_hint(&self) -> (usize, Option<usize>) {
-        let remaining_count = self.len() - self.next_index;
-        (remaining_count, Some(remaining_count))
+    fn for_each<F>(self, mut f: F)
+    where
+        F: FnMut(Self::Item),
+    {
+        self.fold((), |(), x| f(x));
+    }
+
+    #[cfg(feature = "nightly")]
+    fn try_fold<B, F, R>(&mut self, init: B, mut f: F) -> R
+    where
+        F: FnMut(B, Self::Item) -> R,
+        R: Try<Output = B>,
+    {
+        let container = self.container;
+        let mut acc = init;
+        for run in container.runs_in_range(self.next_index, self.end_index) {
+            // advance before calling `f`, so the cursor is past the last element visited
+            // if `f` short-circuits
+            acc = run.iter().try_fold(acc, |acc, x| {
+                self.next_index += 1;
+                f(acc, x)
+            })?;
+        }
+        debug_assert!(self.assert_invariants());
+        R::from_output(acc)
     }
 }
 
@@ -1158,41 +4362,55 @@ where
     T: Copy + Default + Debug,
 {
     fn next_back(&mut self) -> Option<Self::Item> {
-        if self.len() == 0 || self.next_rev_index < self.next_index {
+        if self.next_index == self.end_index {
             None
         } else {
-            let current = self.container.get(self.next_rev_index);
-            // We can't decrement next_rev_index past 0, so we cheat and move next_index
-            // ahead instead. That works since next() must return None once next_rev_index
-            // has crossed next_index.
-            if self.next_rev_index == 0 {
-                self.next_index += 1;
-            } else {
-                self.next_rev_index -= 1;
-            }
+            self.end_index -= 1;
             debug_assert!(self.assert_invariants());
-            current
+            self.container.get(self.end_index)
         }
     }
 
     fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
-        self.next_rev_index = self.next_rev_index.saturating_sub(n);
-        let ret = if self.len() == 0 || self.next_rev_index < self.next_index {
+        // never retreat past the front cursor, so a later `nth()` can't revisit elements
+        if n >= self.end_index - self.next_index {
+            self.end_index = self.next_index;
+            debug_assert!(self.assert_invariants());
             None
         } else {
-            let nth = self.container.get(self.next_rev_index);
-            // We can't decrement next_rev_index past 0, so we cheat and move next_index
-            // ahead instead. That works since next() must return None once next_rev_index
-            // has crossed next_index.
-            if self.next_rev_index == 0 {
-                self.next_index += 1;
-            } else {
-                self.next_rev_index -= 1;
-            }
-            nth
-        };
+            self.end_index -= n;
+            self.next_back()
+        }
+    }
+
+    fn rfold<B, F>(self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, Self::Item) -> B,
+    {
+        // walk whole contiguous runs in reverse rather than mapping each logical index separately
+        self.container
+            .runs_in_range(self.next_index, self.end_index)
+            .rfold(init, |acc, run| run.iter().rfold(acc, &mut f))
+    }
+
+    #[cfg(feature = "nightly")]
+    fn try_rfold<B, F, R>(&mut self, init: B, mut f: F) -> R
+    where
+        F: FnMut(B, Self::Item) -> R,
+        R: Try<Output = B>,
+    {
+        let container = self.container;
+        let mut acc = init;
+        for run in container.runs_in_range(self.next_index, self.end_index).rev() {
+            // retreat before calling `f`, so the cursor is before the last element visited
+            // if `f` short-circuits
+            acc = run.iter().try_rfold(acc, |acc, x| {
+                self.end_index -= 1;
+                f(acc, x)
+            })?;
+        }
         debug_assert!(self.assert_invariants());
-        ret
+        R::from_output(acc)
     }
 }
 
@@ -1201,7 +4419,7 @@ where
     T: Copy + Default + Debug,
 {
     fn len(&self) -> usize {
-        self.container.len()
+        self.end_index - self.next_index
     }
 }
 
@@ -1218,49 +4436,43 @@ where
     // https://stackoverflow.com/questions/25730586/how-can-i-create-my-own-data-structure-with-an-iterator-that-returns-mutable-ref
     // https://stackoverflow.com/questions/27118398/simple-as-possible-example-of-returning-a-mutable-reference-from-your-own-iterat
     fn next(&mut self) -> Option<Self::Item> {
-        let ret = if self.len() == 0 || self.next_index > self.next_rev_index {
+        // the cursors must never cross, or we could hand out two mutable references to one element
+        let ret = if self.next_index >= self.end_index {
             None
         } else {
-            let current = self.container.get_mut(self.next_index);
+            let real_idx = self.container.get_real_index(self.next_index);
             self.next_index += 1;
             // see MutItems example at https://docs.rs/strided/0.2.9/src/strided/base.rs.html
             // per above links, rustc cannot understand that we never return two mutable references to the same object,
-            // so we have to use unsafe code to coerce the return value to the desired lifetime
-            unsafe { mem::transmute(current) }
+            // so we have to go through a raw pointer to produce a reference with the desired lifetime
+            Some(unsafe { &mut *self.data.add(real_idx) })
         };
         debug_assert!(self.assert_invariants());
         ret
     }
 
     fn nth(&mut self, n: usize) -> Option<Self::Item> {
-        self.next_index = min(self.next_index + n, self.len());
-        let ret = if self.len() == 0 || self.next_index > self.next_rev_index {
+        // never advance past the back cursor, so a later `nth_back()` can't revisit elements
+        if n >= self.end_index - self.next_index {
+            self.next_index = self.end_index;
+            debug_assert!(self.assert_invariants());
             None
         } else {
-            let nth = self.container.get_mut(self.next_index);
-            self.next_index += 1;
-            // per above links, rustc cannot understand that we never return two mutable references to the same object,
-            // so we have to use unsafe code to coerce the return value to the desired lifetime
-            unsafe { mem::transmute(nth) }
-        };
-        debug_assert!(self.assert_invariants());
-        ret
+            self.next_index += n;
+            self.next()
+        }
     }
 
     fn count(self) -> usize {
-        self.len() - self.next_index
+        self.len()
     }
 
-    fn last(self) -> Option<Self::Item> {
-        if self.len() == 0 {
-            None
-        } else {
-            self.container.get_mut(self.len() - 1)
-        }
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let remaining_count = self.len() - self.next_index;
+        let remaining_count = self.len();
         (remaining_count, Some(remaining_count))
     }
 }
@@ -1270,45 +4482,30 @@ where
     T: Copy + Default + Debug,
 {
     fn next_back(&mut self) -> Option<Self::Item> {
-        if self.len() == 0 || self.next_rev_index < self.next_index {
+        // the cursors must never cross, or we could hand out two mutable references to one element
+        let ret = if self.next_index >= self.end_index {
             None
         } else {
-            let current = self.container.get(self.next_rev_index);
-            // We can't decrement next_rev_index past 0, so we cheat and move next_index
-            // ahead instead. That works since next() must return None once next_rev_index
-            // has crossed next_index.
-            if self.next_rev_index == 0 {
-                self.next_index += 1;
-            } else {
-                self.next_rev_index -= 1;
-            }
-            debug_assert!(self.assert_invariants());
+            self.end_index -= 1;
+            let real_idx = self.container.get_real_index(self.end_index);
             // per above links, rustc cannot understand that we never return two mutable references to the same object,
-            // so we have to use unsafe code to coerce the return value to the desired lifetime
-            unsafe { mem::transmute(current) }
-        }
+            // so we have to go through a raw pointer to produce a reference with the desired lifetime
+            Some(unsafe { &mut *self.data.add(real_idx) })
+        };
+        debug_assert!(self.assert_invariants());
+        ret
     }
 
     fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
-        self.next_rev_index = self.next_rev_index.saturating_sub(n);
-        let ret = if self.len() == 0 || self.next_rev_index < self.next_index {
+        // never retreat past the front cursor, so a later `nth()` can't revisit elements
+        if n >= self.end_index - self.next_index {
+            self.end_index = self.next_index;
+            debug_assert!(self.assert_invariants());
             None
         } else {
-            let nth = self.container.get(self.next_rev_index);
-            // We can't decrement next_rev_index past 0, so we cheat and move next_index
-            // ahead instead. That works since next() must return None once next_rev_index
-            // has crossed next_index.
-            if self.next_rev_index == 0 {
-                self.next_index += 1;
-            } else {
-                self.next_rev_index -= 1;
-            }
-            // per above links, rustc cannot understand that we never return two mutable references to the same object,
-            // so we have to use unsafe code to coerce the return value to the desired lifetime
-            unsafe { mem::transmute(nth) }
-        };
-        debug_assert!(self.assert_invariants());
-        ret
+            self.end_index -= n;
+            self.next_back()
+        }
     }
 }
 
@@ -1317,12 +4514,148 @@ where
     T: Copy + Default + Debug,
 {
     fn len(&self) -> usize {
-        self.container.len()
+        self.end_index - self.next_index
     }
 }
 
 impl<T> FusedIterator for IterMut<'_, T> where T: Copy + Default + Debug {}
 
+impl<'a, T> Iterator for RChunks<'a, T>
+where
+    T: Copy + Default + Debug,
+{
+    type Item = RotatedSlice<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.start_index == self.end_index {
+            None
+        } else {
+            let chunk_start_index = if self.end_index - self.start_index > self.chunk_size {
+                self.end_index - self.chunk_size
+            } else {
+                self.start_index
+            };
+            let chunk = RotatedSlice {
+                container: self.container,
+                start_index: chunk_start_index,
+                end_index: self.end_index,
+            };
+            self.end_index = chunk_start_index;
+            Some(chunk)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining_len = self.end_index - self.start_index;
+        let remaining_count = remaining_len.div_ceil(self.chunk_size);
+        (remaining_count, Some(remaining_count))
+    }
+}
+
+impl<T> FusedIterator for RChunks<'_, T> where T: Copy + Default + Debug {}
+
+impl<'a, T> Iterator for ChunksMut<'a, T>
+where
+    T: Copy + Default + Debug,
+{
+    type Item = Vec<&'a mut T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.iter.len() == 0 {
+            None
+        } else {
+            Some(self.iter.by_ref().take(self.chunk_size).collect())
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining_count = self.iter.len().div_ceil(self.chunk_size);
+        (remaining_count, Some(remaining_count))
+    }
+}
+
+impl<T> FusedIterator for ChunksMut<'_, T> where T: Copy + Default + Debug {}
+
+impl<T, F> Iterator for GroupBy<'_, T, F>
+where
+    T: Copy + Default + Debug,
+    F: FnMut(&T, &T) -> bool,
+{
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let len = self.container.len();
+        if self.index >= len {
+            return None;
+        }
+        let mut group = Vec::new();
+        group.push(self.container[self.index]);
+        self.index += 1;
+        while self.index < len
+            && (self.same_group)(&self.container[self.index - 1], &self.container[self.index])
+        {
+            group.push(self.container[self.index]);
+            self.index += 1;
+        }
+        Some(group)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining_len = self.container.len() - self.index;
+        (min(1, remaining_len), Some(remaining_len))
+    }
+}
+
+impl<T, F> FusedIterator for GroupBy<'_, T, F>
+where
+    T: Copy + Default + Debug,
+    F: FnMut(&T, &T) -> bool,
+{
+}
+
+impl<T, F> Iterator for ExtractIf<'_, T, F>
+where
+    T: Copy + Default + Debug,
+    F: FnMut(&mut T) -> bool,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let data = &mut self.container.data;
+        while self.read_index < data.len() {
+            let mut current = data[self.read_index];
+            self.read_index += 1;
+            if (self.pred)(&mut current) {
+                return Some(current);
+            }
+            data[self.write_index] = current;
+            self.write_index += 1;
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.container.data.len() - self.read_index))
+    }
+}
+
+impl<T, F> Drop for ExtractIf<'_, T, F>
+where
+    T: Copy + Default + Debug,
+    F: FnMut(&mut T) -> bool,
+{
+    fn drop(&mut self) {
+        // retain all unexamined elements, then rebuild the offset array
+        let data = &mut self.container.data;
+        let len = data.len();
+        data.copy_within(self.read_index..len, self.write_index);
+        data.truncate(self.write_index + len - self.read_index);
+        self.container.start_indexes.clear();
+        self.container.init();
+        debug_assert!(self.container.assert_invariants());
+    }
+}
+
 impl<'a, T> IntoIterator for &'a RotatedVec<T>
 where
     T: Copy + Default + Debug,
@@ -1362,7 +4695,7 @@ where
     }
 }
 
-impl<'a, T> Iterator for IntoIter<T>
+impl<T> Iterator for IntoIter<T>
 where
     T: Copy + Default + Debug,
 {
@@ -1378,8 +4711,24 @@ where
             Some(current)
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining_count = self.len();
+        (remaining_count, Some(remaining_count))
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T>
+where
+    T: Copy + Default + Debug,
+{
+    fn len(&self) -> usize {
+        self.vec.len() - self.next_index
+    }
 }
 
+impl<T> FusedIterator for IntoIter<T> where T: Copy + Default + Debug {}
+
 impl<'a, T> From<&'a [T]> for RotatedVec<T>
 where
     T: Copy + Default + Debug,
@@ -1388,67 +4737,126 @@ where
         let mut this = RotatedVec {
             data: slice.to_vec(),
             start_indexes: Vec::new(),
+            #[cfg(feature = "metrics")]
+            move_count: 0,
         };
         this.init();
         this
     }
 }
 
-impl<T> From<Vec<T>> for RotatedVec<T>
-where
-    T: Copy + Default + Debug,
-{
+impl<T> From<Vec<T>> for RotatedVec<T> {
     fn from(vec: Vec<T>) -> Self {
         let mut this = RotatedVec {
             data: vec,
             start_indexes: Vec::new(),
+            #[cfg(feature = "metrics")]
+            move_count: 0,
         };
         this.init();
         this
     }
 }
 
-impl<T> Into<Vec<T>> for RotatedVec<T>
-where
-    T: Copy + Default + Debug,
-{
-    fn into(mut self) -> Vec<T> {
-        // un-rotate the data array in-place and steal it from self
-        for (i, &pivot_offset) in self.start_indexes.iter().enumerate() {
-            let subarray_start_idx = Self::get_array_idx_from_subarray_idx(i);
-            let subarray_len = if i == self.start_indexes.len() - 1 {
-                self.data.len() - subarray_start_idx
-            } else {
-                i + 1
-            };
-            let subarray_end_idx = subarray_start_idx + subarray_len;
-            let subarray = &mut self.data[subarray_start_idx..subarray_end_idx];
-            // un-rotate subarray in-place
-            subarray.rotate_left(pivot_offset);
-        }
-        // steal data array
-        self.data
+impl<T> From<RotatedVec<T>> for Vec<T> {
+    fn from(mut vec: RotatedVec<T>) -> Vec<T> {
+        // un-rotate the data array in-place and steal it from vec
+        vec.normalize();
+        vec.data
+    }
+}
+
+impl<T> FromIterator<T> for RotatedVec<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let iter = iter.into_iter();
+        // if the exact length is known, allocate both arrays up front
+        let mut this = match iter.size_hint() {
+            (lower, Some(upper)) if lower == upper => RotatedVec::with_capacity(lower),
+            _ => RotatedVec::new(),
+        };
+        this.data.extend(iter);
+        this.init();
+        this
     }
 }
 
-impl<T> FromIterator<T> for RotatedVec<T>
+#[cfg(feature = "rayon")]
+impl<T> rayon::iter::FromParallelIterator<T> for RotatedVec<T>
 where
-    T: Copy + Default + Debug,
+    T: Copy + Default + Debug + Send,
 {
-    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+    /// Collects a parallel iterator into a `RotatedVec`, in the iterator's
+    /// order.
+    ///
+    /// The elements are collected into a `Vec` in parallel, and the
+    /// subarray layout is then built over it in logical order.
+    ///
+    /// This is only available when the `rayon` feature is enabled.
+    fn from_par_iter<I>(par_iter: I) -> Self
+    where
+        I: rayon::iter::IntoParallelIterator<Item = T>,
+    {
         let mut this = RotatedVec {
-            data: Vec::from_iter(iter.into_iter()),
+            data: Vec::from_par_iter(par_iter),
             start_indexes: Vec::new(),
+            #[cfg(feature = "metrics")]
+            move_count: 0,
         };
         this.init();
         this
     }
 }
 
-impl<T> Default for RotatedVec<T>
+#[cfg(feature = "proptest")]
+impl<T> proptest::arbitrary::Arbitrary for RotatedVec<T>
 where
-    T: Copy + Default + Debug,
+    T: proptest::arbitrary::Arbitrary + Copy + Default + Debug + 'static,
 {
+    type Parameters = <Vec<T> as proptest::arbitrary::Arbitrary>::Parameters;
+    type Strategy = proptest::strategy::BoxedStrategy<Self>;
+
+    /// Generates a `RotatedVec` with arbitrary contents and an arbitrary
+    /// internal layout, i.e. with each subarray rotated by a random offset
+    /// (except a partially full last subarray, which must stay in order).
+    ///
+    /// This is only available when the `proptest` feature is enabled.
+    fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+        use proptest::prelude::*;
+        any_with::<Vec<T>>(args)
+            .prop_flat_map(|data| {
+                let len = data.len();
+                let subarray_count = if len == 0 {
+                    0
+                } else {
+                    Self::get_subarray_idx_from_array_idx(len - 1) + 1
+                };
+                let pivots: Vec<_> = (0..subarray_count)
+                    .map(|subarray_idx| {
+                        let subarray_start_idx = Self::get_array_idx_from_subarray_idx(subarray_idx);
+                        if len - subarray_start_idx > subarray_idx {
+                            0..subarray_idx + 1
+                        } else {
+                            // a partially full last subarray must stay in order
+                            0..1
+                        }
+                    })
+                    .collect();
+                (Just(data), pivots)
+            })
+            .prop_map(|(mut data, start_indexes)| {
+                for (subarray_idx, &pivot_offset) in start_indexes.iter().enumerate() {
+                    let subarray_start_idx = Self::get_array_idx_from_subarray_idx(subarray_idx);
+                    let subarray_end_idx = min(subarray_start_idx + subarray_idx + 1, data.len());
+                    data[subarray_start_idx..subarray_end_idx].rotate_right(pivot_offset);
+                }
+                RotatedVec::from_raw_parts_checked(data, start_indexes)
+                    .expect("generated an invalid layout")
+            })
+            .boxed()
+    }
+}
+
+impl<T> Default for RotatedVec<T> {
     #[inline]
     fn default() -> RotatedVec<T> {
         RotatedVec::new()
@@ -0,0 +1,224 @@
+//! A double-ended queue stored in a `RotatedVec`.
+//!
+//! Operations at the back and access to either end are constant-time, as
+//! with `std::collections::VecDeque`. Operations at the front are `O(√n)`
+//! rather than amortized constant-time, which is the price of the
+//! `RotatedVec` layout: in exchange, insertions and removals in the middle of
+//! a `RotatedVec` are `O(√n)` rather than `O(n)`.
+
+use core::fmt::Debug;
+
+use crate::RotatedVec;
+
+/// A double-ended queue implemented with a `RotatedVec`.
+///
+/// # Examples
+///
+/// ```
+/// use rotated_vec::rotated_deque::RotatedDeque;
+///
+/// let mut deque = RotatedDeque::new();
+/// deque.push_back(2);
+/// deque.push_back(3);
+/// deque.push_front(1);
+///
+/// assert_eq!(deque.front(), Some(&1));
+/// assert_eq!(deque.back(), Some(&3));
+/// assert_eq!(deque.len(), 3);
+///
+/// assert_eq!(deque.pop_front(), Some(1));
+/// assert_eq!(deque.pop_back(), Some(3));
+/// assert_eq!(deque.pop_back(), Some(2));
+/// assert_eq!(deque.pop_back(), None);
+/// ```
+#[derive(Clone, Debug)]
+pub struct RotatedDeque<T> {
+    data: RotatedVec<T>,
+}
+
+impl<T> RotatedDeque<T>
+where
+    T: Copy + Default + Debug,
+{
+    /// Creates an empty `RotatedDeque`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::rotated_deque::RotatedDeque;
+    ///
+    /// let mut deque = RotatedDeque::new();
+    /// deque.push_back(4);
+    /// ```
+    pub fn new() -> Self {
+        RotatedDeque {
+            data: RotatedVec::new(),
+        }
+    }
+
+    /// Returns a reference to the front element, or `None` if the deque is
+    /// empty.
+    ///
+    /// This is a constant-time operation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::rotated_deque::RotatedDeque;
+    ///
+    /// let mut deque = RotatedDeque::new();
+    /// assert_eq!(deque.front(), None);
+    ///
+    /// deque.push_back(1);
+    /// deque.push_back(2);
+    /// assert_eq!(deque.front(), Some(&1));
+    /// ```
+    pub fn front(&self) -> Option<&T> {
+        self.data.get(0)
+    }
+
+    /// Returns a reference to the back element, or `None` if the deque is
+    /// empty.
+    ///
+    /// This is a constant-time operation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::rotated_deque::RotatedDeque;
+    ///
+    /// let mut deque = RotatedDeque::new();
+    /// assert_eq!(deque.back(), None);
+    ///
+    /// deque.push_back(1);
+    /// deque.push_back(2);
+    /// assert_eq!(deque.back(), Some(&2));
+    /// ```
+    pub fn back(&self) -> Option<&T> {
+        self.data.len().checked_sub(1).and_then(|index| self.data.get(index))
+    }
+
+    /// Prepends an element to the deque.
+    ///
+    /// This is an `O(√n)` operation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::rotated_deque::RotatedDeque;
+    ///
+    /// let mut deque = RotatedDeque::new();
+    /// deque.push_front(1);
+    /// deque.push_front(2);
+    /// assert_eq!(deque.front(), Some(&2));
+    /// ```
+    pub fn push_front(&mut self, value: T) {
+        self.data.push_front(value);
+    }
+
+    /// Appends an element to the back of the deque.
+    ///
+    /// This is a constant-time operation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::rotated_deque::RotatedDeque;
+    ///
+    /// let mut deque = RotatedDeque::new();
+    /// deque.push_back(1);
+    /// deque.push_back(3);
+    /// assert_eq!(deque.back(), Some(&3));
+    /// ```
+    pub fn push_back(&mut self, value: T) {
+        self.data.push(value);
+    }
+
+    /// Removes the first element and returns it, or `None` if the deque is
+    /// empty.
+    ///
+    /// This is an `O(√n)` operation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::rotated_deque::RotatedDeque;
+    ///
+    /// let mut deque = RotatedDeque::new();
+    /// deque.push_back(1);
+    /// deque.push_back(2);
+    ///
+    /// assert_eq!(deque.pop_front(), Some(1));
+    /// assert_eq!(deque.pop_front(), Some(2));
+    /// assert_eq!(deque.pop_front(), None);
+    /// ```
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.data.pop_front()
+    }
+
+    /// Removes the last element and returns it, or `None` if the deque is
+    /// empty.
+    ///
+    /// This is a constant-time operation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::rotated_deque::RotatedDeque;
+    ///
+    /// let mut deque = RotatedDeque::new();
+    /// deque.push_back(1);
+    /// deque.push_back(3);
+    ///
+    /// assert_eq!(deque.pop_back(), Some(3));
+    /// assert_eq!(deque.pop_back(), Some(1));
+    /// assert_eq!(deque.pop_back(), None);
+    /// ```
+    pub fn pop_back(&mut self) -> Option<T> {
+        self.data.pop()
+    }
+
+    /// Returns the number of elements in the deque.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::rotated_deque::RotatedDeque;
+    ///
+    /// let mut deque = RotatedDeque::new();
+    /// deque.push_back(1);
+    /// deque.push_front(3);
+    ///
+    /// assert_eq!(deque.len(), 2);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns `true` if the deque is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rotated_vec::rotated_deque::RotatedDeque;
+    ///
+    /// let mut deque = RotatedDeque::new();
+    /// assert!(deque.is_empty());
+    ///
+    /// deque.push_back(1);
+    /// assert!(!deque.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+}
+
+impl<T> Default for RotatedDeque<T>
+where
+    T: Copy + Default + Debug,
+{
+    #[inline]
+    fn default() -> RotatedDeque<T> {
+        RotatedDeque::new()
+    }
+}
@@ -0,0 +1,36 @@
+#![cfg(feature = "proptest")]
+
+extern crate proptest;
+use self::proptest::prelude::*;
+use rotated_vec::RotatedVec;
+
+proptest! {
+    #[test]
+    fn arbitrary_layout_is_valid(v in any::<RotatedVec<u8>>()) {
+        let (data, start_indexes) = v.clone().into_raw_parts();
+        // un-rotate each subarray by hand to recover the logical order
+        let mut unrotated = data.clone();
+        let mut subarray_start_idx = 0;
+        for (subarray_idx, &pivot_offset) in start_indexes.iter().enumerate() {
+            let subarray_end_idx = (subarray_start_idx + subarray_idx + 1).min(unrotated.len());
+            unrotated[subarray_start_idx..subarray_end_idx].rotate_left(pivot_offset);
+            subarray_start_idx = subarray_end_idx;
+        }
+        prop_assert!(v.iter().eq(unrotated.iter()));
+        prop_assert_eq!(RotatedVec::from_raw_parts_checked(data, start_indexes), Ok(v));
+    }
+}
+
+#[test]
+fn arbitrary_layouts_are_rotated() {
+    use proptest::strategy::ValueTree;
+    use proptest::test_runner::TestRunner;
+
+    let mut runner = TestRunner::deterministic();
+    let strategy = any::<RotatedVec<u8>>();
+    let rotated = (0..100)
+        .map(|_| strategy.new_tree(&mut runner).unwrap().current())
+        .filter(|v| v.as_logical_slice().is_none())
+        .count();
+    assert!(rotated > 0);
+}
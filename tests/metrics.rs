@@ -0,0 +1,107 @@
+#![cfg(feature = "metrics")]
+
+extern crate proptest;
+use self::proptest::prelude::*;
+use rotated_vec::RotatedVec;
+
+// a generous constant factor over the number of subarrays, which is about √(2n)
+fn move_bound(len: usize) -> u64 {
+    4 * (((2 * len) as f64).sqrt() as u64 + 1)
+}
+
+#[test]
+fn insert_front_moves_sqrt_n() {
+    let len = 1_000_000;
+    let mut v: RotatedVec<u32> = (0..len as u32).collect();
+    assert_eq!(v.take_move_count(), 0);
+    v.insert(0, 0);
+    let moves = v.take_move_count();
+    assert!(moves <= move_bound(len), "{} moves", moves);
+    assert!(moves < len as u64 / 100);
+    v.remove(0);
+    let moves = v.take_move_count();
+    assert!(moves <= move_bound(len), "{} moves", moves);
+}
+
+proptest! {
+    #[test]
+    fn insert_remove_moves_bounded((vec, i) in any::<Vec<u8>>().prop_flat_map(|vec| {
+        let len = vec.len();
+        (Just(vec), 0..=len)
+    }), x: u8) {
+        let mut v: RotatedVec<u8> = vec.into();
+        let len = v.len();
+        v.insert(i, x);
+        prop_assert!(v.take_move_count() <= move_bound(len + 1));
+        v.remove(i);
+        prop_assert!(v.take_move_count() <= move_bound(len + 1));
+    }
+}
+
+#[test]
+fn append_moves_smaller_side() {
+    let small: RotatedVec<u32> = (0..10).collect();
+    let large: RotatedVec<u32> = (10..100_010).collect();
+    let expected: RotatedVec<u32> = (0..100_010).collect();
+
+    // small into large: the small side is inserted at the front of the large side
+    let mut v = small.clone();
+    let mut other = large.clone();
+    v.append(&mut other);
+    assert_eq!(v, expected);
+    assert!(other.is_empty());
+    let moves = v.take_move_count();
+    assert!(moves <= 10 * move_bound(100_010), "{} moves", moves);
+    assert!(moves < 100_000 / 2, "{} moves", moves);
+
+    // large into small: the small side is copied after the large side
+    let mut v = large.clone();
+    let mut other = small.clone();
+    v.append(&mut other);
+    let expected: RotatedVec<u32> = (10..100_010).chain(0..10).collect();
+    assert_eq!(v, expected);
+    assert_eq!(v.take_move_count(), 10);
+}
+
+#[test]
+fn split_off_at_subarray_boundary_moves_fewer() {
+    let len = 100_000;
+    let mut v: RotatedVec<u32> = (1..len as u32).collect();
+    // rotate every subarray
+    v.push_front(0);
+    v.take_move_count();
+    // subarray 400 starts at index 400 * 401 / 2
+    let boundary = 400 * 401 / 2;
+
+    let mut at_boundary = v.clone();
+    let tail = at_boundary.split_off(boundary);
+    assert!(at_boundary.iter().cloned().eq(0..boundary as u32));
+    assert!(tail.iter().cloned().eq(boundary as u32..len as u32));
+    let boundary_moves = at_boundary.take_move_count();
+    assert_eq!(boundary_moves, (len - boundary) as u64);
+
+    let mut off_boundary = v.clone();
+    let tail = off_boundary.split_off(boundary + 1);
+    assert!(off_boundary.iter().cloned().eq(0..boundary as u32 + 1));
+    assert!(tail.iter().cloned().eq(boundary as u32 + 1..len as u32));
+    let off_boundary_moves = off_boundary.take_move_count();
+    assert!(boundary_moves < off_boundary_moves, "{} vs {} moves", boundary_moves, off_boundary_moves);
+}
+
+#[test]
+fn remove_last_moves_sqrt_n() {
+    // a triangular number, so all subarrays start out full
+    let len = 1413 * 1414 / 2;
+    let mut v: RotatedVec<u32> = (0..len as u32).collect();
+    // inserting at the front rotates every full subarray and starts a new one
+    v.insert(0, 0);
+    v.take_move_count();
+    // the new last subarray holds only the last element, so nothing else moves
+    assert_eq!(v.remove(len), len as u32 - 1);
+    assert_eq!(v.take_move_count(), 0);
+    // now the last subarray is full and rotated, so it has to be un-rotated
+    assert_eq!(v.remove(len - 1), len as u32 - 2);
+    let moves = v.take_move_count();
+    assert!(moves > 0);
+    assert!(moves <= move_bound(len), "{} moves", moves);
+}
@@ -0,0 +1,48 @@
+#![cfg(feature = "nightly")]
+#![feature(extend_one)]
+
+extern crate proptest;
+use self::proptest::prelude::*;
+use rotated_vec::RotatedVec;
+
+proptest! {
+    #[test]
+    fn extend_one(vec: Vec<u8>, x: u8) {
+        let mut pushed: RotatedVec<u8> = vec.clone().into();
+        let mut extended = pushed.clone();
+        pushed.push(x);
+        let mut extended_by_ref = extended.clone();
+        extended.extend_one(x);
+        extended_by_ref.extend_one(&x);
+        prop_assert_eq!(extended.clone().into_raw_parts(), extended_by_ref.into_raw_parts());
+        prop_assert_eq!(pushed.into_raw_parts(), extended.into_raw_parts());
+    }
+
+    #[test]
+    fn extend_reserve(vec: Vec<u8>, additional in 0..1000usize) {
+        let mut v: RotatedVec<u8> = vec.into();
+        // `Extend<&u8>` is implemented too, so name the element type explicitly
+        Extend::<u8>::extend_reserve(&mut v, additional);
+        prop_assert!(v.capacity() >= v.len() + additional);
+    }
+
+    #[test]
+    fn try_fold_short_circuits(vec: Vec<u8>, x: u8) {
+        let mut v = RotatedVec::new();
+        for &y in vec.iter().rev() {
+            v.push_front(y);
+        }
+        // summing until `x` stops at the same element, and leaves the iterators at the same place
+        let sum_until = |acc: u64, &y: &u8| if y == x { None } else { Some(acc + u64::from(y)) };
+        let (mut iter, mut vec_iter) = (v.iter(), vec.iter());
+        prop_assert_eq!(iter.try_fold(0, sum_until), vec_iter.try_fold(0, sum_until));
+        prop_assert!(iter.eq(vec_iter));
+        let (mut iter, mut vec_iter) = (v.iter(), vec.iter());
+        prop_assert_eq!(iter.try_rfold(0, sum_until), vec_iter.try_rfold(0, sum_until));
+        prop_assert!(iter.eq(vec_iter));
+        let (mut iter, mut vec_iter) = (v.iter(), vec.iter());
+        prop_assert_eq!(iter.position(|&y| y == x), vec_iter.position(|&y| y == x));
+        prop_assert_eq!(iter.rfind(|&&y| y == x), vec_iter.rfind(|&&y| y == x));
+        prop_assert!(iter.eq(vec_iter));
+    }
+}
@@ -0,0 +1,59 @@
+// exercises the public API from a crate that only has access to `core` and
+// `alloc`; run with `--no-default-features` to also build the library without `std`
+#![no_std]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use rotated_vec::RotatedVec;
+
+#[test]
+fn insert_remove_without_std() {
+    let mut v: RotatedVec<u32> = RotatedVec::new();
+    for i in 0..100 {
+        v.insert(0, i);
+    }
+    assert!(v.iter().cloned().eq((0..100).rev()));
+    for i in 0..50 {
+        assert_eq!(v.remove(0), 99 - i);
+    }
+    let vec: Vec<u32> = v.into();
+    assert!(vec.into_iter().eq((0..50).rev()));
+}
+
+// the subarray index computation used to go through a truncating `f64` square
+// root, which broke down past 2^29 elements; zero-sized elements let us build
+// such vectors without allocating their contents
+#[cfg(target_pointer_width = "64")]
+#[test]
+fn subarray_count_past_u32_range() {
+    for &len in &[1usize << 29, (1 << 31) + 12_345, 1 << 33] {
+        let v: RotatedVec<()> = alloc::vec![(); len].into();
+        let mut expected_count = 0;
+        while expected_count * (expected_count + 1) / 2 < len {
+            expected_count += 1;
+        }
+        assert_eq!(v.subarray_count(), expected_count);
+        assert_eq!(v.get(len - 1), Some(&()));
+        assert_eq!(v.get(len), None);
+    }
+}
+
+// on 32-bit targets, `8 * len + 1` overflows past 2^29 elements, and the start of
+// the subarray one past the last overflows for lengths near `usize::MAX`
+#[cfg(target_pointer_width = "32")]
+#[test]
+fn subarray_count_past_u32_overflow() {
+    for &len in &[(1usize << 29) - 1, 1 << 29, (1 << 29) + 1, 1 << 31, usize::MAX] {
+        let v: RotatedVec<()> = alloc::vec![(); len].into();
+        // compute the expected count in 64 bits, so the test itself can't wrap around
+        let mut expected_count = 0u64;
+        while expected_count * (expected_count + 1) / 2 < len as u64 {
+            expected_count += 1;
+        }
+        assert_eq!(v.subarray_count() as u64, expected_count);
+        assert_eq!(v.subarray_len(v.subarray_count() - 1).map(|n| n as u64),
+            Some(len as u64 - (expected_count - 1) * expected_count / 2));
+        assert_eq!(v.get(len - 1), Some(&()));
+    }
+}
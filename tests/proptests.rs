@@ -1,8 +1,14 @@
 // adapted from https://github.com/ssomers/rust_bench_btreeset_intersection/blob/master/src/tests/set.rs
 extern crate proptest;
 use self::proptest::prelude::*;
+use rotated_vec::binary_heap::BinaryHeap;
+use rotated_vec::rotated_deque::RotatedDeque;
 use rotated_vec::RotatedVec;
-use std::cmp::min;
+use std::cell::Cell;
+use std::cmp::{min, Ordering};
+use std::collections::VecDeque;
+use std::fmt::Debug;
+use std::rc::Rc;
 
 prop_compose! {
     fn arbitrary_instance()
@@ -13,6 +19,38 @@ prop_compose! {
     }
 }
 
+// building by repeated insertion at the front leaves interior subarrays rotated,
+// unlike building from a `Vec`
+fn rotated_from<T>(items: &[T]) -> RotatedVec<T>
+where
+    T: Copy + Default + Debug,
+{
+    let mut v = RotatedVec::new();
+    for &x in items.iter().rev() {
+        v.push_front(x);
+    }
+    v
+}
+
+prop_compose! {
+    fn arbitrary_rotated_instance()
+                    (vec: Vec<u8>)
+                    -> RotatedVec<u8>
+    {
+        rotated_from(&vec)
+    }
+}
+
+prop_compose! {
+    fn arbitrary_sorted_rotated_instance()
+                    (mut vec: Vec<u8>)
+                    -> RotatedVec<u8>
+    {
+        vec.sort_unstable();
+        rotated_from(&vec)
+    }
+}
+
 // note that we can return an index up to len() inclusive.
 // this is necessary to provide a valid range to the RNG for empty instances.
 prop_compose! {
@@ -25,6 +63,16 @@ prop_compose! {
     }
 }
 
+prop_compose! {
+    fn arbitrary_rotated_instance_with_index()
+                    (v in arbitrary_rotated_instance())
+                    (index in 0..=v.len(), v in Just(v))
+                    -> (RotatedVec<u8>, usize)
+    {
+        (v, index)
+    }
+}
+
 proptest! {
     #[test]
     fn push_pop(mut v in arbitrary_instance(), x: u8) {
@@ -76,4 +124,1665 @@ proptest! {
         iter_mut.next_back();
         prop_assert!(iter_mut.next().is_none());
     }
+
+    #[test]
+    fn deque_ops(mut v in arbitrary_instance(), ops: Vec<(u8, u8)>) {
+        let mut deque: VecDeque<u8> = v.iter().cloned().collect();
+        for (op, x) in ops {
+            match op % 4 {
+                0 => { v.push_front(x); deque.push_front(x); }
+                1 => { v.push(x); deque.push_back(x); }
+                2 => prop_assert_eq!(v.pop_front(), deque.pop_front()),
+                _ => prop_assert_eq!(v.pop(), deque.pop_back()),
+            }
+            prop_assert_eq!(v.len(), deque.len());
+        }
+        prop_assert!(v.iter().eq(deque.iter()));
+    }
+
+    #[test]
+    fn debug_logical_order(v in arbitrary_rotated_instance()) {
+        let vec: Vec<u8> = v.clone().into();
+        prop_assert_eq!(format!("{:?}", v), format!("{:?}", vec));
+    }
+
+    #[test]
+    fn clone_from_reuses_capacity(vs in prop::collection::vec(arbitrary_rotated_instance(), 1..8)) {
+        let mut target: RotatedVec<u8> = RotatedVec::with_capacity(256);
+        let capacity = target.capacity();
+        for v in &vs {
+            target.clone_from(v);
+            prop_assert_eq!(&target, v);
+            prop_assert_eq!(target.capacity(), capacity);
+        }
+    }
+
+    #[test]
+    fn mixed_nth_nth_back(v in arbitrary_rotated_instance(), ops: Vec<(bool, u8)>) {
+        let vec: Vec<u8> = v.clone().into();
+        let mut iter = v.iter();
+        let mut vec_iter = vec.iter();
+        for (back, n) in ops {
+            let n = n as usize % (vec.len() + 2);
+            if back {
+                prop_assert_eq!(iter.nth_back(n), vec_iter.nth_back(n));
+            } else {
+                prop_assert_eq!(iter.nth(n), vec_iter.nth(n));
+            }
+        }
+        prop_assert!(iter.eq(vec_iter));
+    }
+
+    #[test]
+    fn mixed_nth_nth_back_mut(mut v in arbitrary_rotated_instance(), ops: Vec<(bool, u8)>) {
+        let mut vec: Vec<u8> = v.clone().into();
+        let len = vec.len();
+        let mut iter = v.iter_mut();
+        let mut vec_iter = vec.iter_mut();
+        for (back, n) in ops {
+            let n = n as usize % (len + 2);
+            if back {
+                prop_assert_eq!(iter.nth_back(n), vec_iter.nth_back(n));
+            } else {
+                prop_assert_eq!(iter.nth(n), vec_iter.nth(n));
+            }
+        }
+        prop_assert!(iter.eq(vec_iter));
+    }
+
+    #[test]
+    fn logical_slice_from_vec(v in arbitrary_instance()) {
+        let vec: Vec<u8> = v.clone().into();
+        prop_assert_eq!(v.as_logical_slice(), Some(&vec[..]));
+    }
+
+    #[test]
+    fn logical_slice_rotated(v in arbitrary_rotated_instance()) {
+        let vec: Vec<u8> = v.clone().into();
+        if v.real_indices().eq(0..v.len()) {
+            prop_assert_eq!(v.as_logical_slice(), Some(&vec[..]));
+        } else {
+            prop_assert!(v.as_logical_slice().is_none());
+        }
+    }
+
+    #[test]
+    fn split_first_last(v in arbitrary_rotated_instance()) {
+        let vec: Vec<u8> = v.clone().into();
+        match (v.split_first(), vec.split_first()) {
+            (Some((x, rest)), Some((y, vec_rest))) => {
+                prop_assert_eq!(x, y);
+                prop_assert_eq!(rest.len(), vec_rest.len());
+                prop_assert!(rest.iter().eq(vec_rest.iter()));
+            }
+            (None, None) => {}
+            _ => prop_assert!(false),
+        }
+        match (v.split_last(), vec.split_last()) {
+            (Some((x, rest)), Some((y, vec_rest))) => {
+                prop_assert_eq!(x, y);
+                prop_assert_eq!(rest.len(), vec_rest.len());
+                prop_assert!(rest.iter().eq(vec_rest.iter()));
+            }
+            (None, None) => {}
+            _ => prop_assert!(false),
+        }
+    }
+
+    #[test]
+    fn copy_from_slice((mut v, i) in arbitrary_rotated_instance_with_index(), src: Vec<u8>) {
+        let mut vec: Vec<u8> = v.clone().into();
+        let end = min(i + src.len(), vec.len());
+        let src = &src[..end - i];
+        v.copy_from_slice(i..end, src);
+        vec[i..end].copy_from_slice(src);
+        prop_assert_eq!(v, vec.into());
+    }
+
+    #[test]
+    fn rchunks(v in arbitrary_rotated_instance(), chunk_size in 1..20usize) {
+        let vec: Vec<u8> = v.clone().into();
+        let chunks: Vec<Vec<u8>> = v.rchunks(chunk_size).map(|c| c.iter().cloned().collect()).collect();
+        let vec_chunks: Vec<Vec<u8>> = vec.rchunks(chunk_size).map(|c| c.to_vec()).collect();
+        prop_assert_eq!(v.rchunks(chunk_size).size_hint().0, vec_chunks.len());
+        prop_assert_eq!(chunks, vec_chunks);
+    }
+
+    #[test]
+    fn partition_point(mut v in arbitrary_rotated_instance(), x: u8) {
+        v.sort();
+        let vec: Vec<u8> = v.clone().into();
+        prop_assert_eq!(v.partition_point(|&y| y < x), vec.partition_point(|&y| y < x));
+        prop_assert_eq!(v.partition_point(|_| true), v.len());
+        prop_assert_eq!(v.partition_point(|_| false), 0);
+    }
+
+    #[test]
+    fn subarray_layout(v in arbitrary_rotated_instance()) {
+        let count = v.subarray_count();
+        // the first `count - 1` subarrays are full, so hold a triangular number of elements
+        let full_len = (count.saturating_sub(1) * count) / 2;
+        prop_assert!(v.len() >= full_len);
+        prop_assert!(v.len() <= full_len + count);
+        let mut total = 0;
+        for i in 0..count {
+            let len = v.subarray_len(i).unwrap();
+            if i + 1 < count {
+                prop_assert_eq!(len, i + 1);
+            } else {
+                prop_assert!(len >= 1 && len <= i + 1);
+            }
+            total += len;
+        }
+        prop_assert_eq!(total, v.len());
+        prop_assert_eq!(v.subarray_len(count), None);
+    }
+
+    #[test]
+    fn extract_if(mut v in arbitrary_rotated_instance(), take in 0..100usize) {
+        let mut vec: Vec<u8> = v.clone().into();
+        let extracted: Vec<u8> = v.extract_if(|x| *x % 2 == 1).take(take).collect();
+        let mut vec_extracted = Vec::new();
+        let mut i = 0;
+        while i < vec.len() {
+            if vec_extracted.len() < take && vec[i] % 2 == 1 {
+                vec_extracted.push(vec.remove(i));
+            } else {
+                i += 1;
+            }
+        }
+        prop_assert_eq!(extracted, vec_extracted);
+        prop_assert_eq!(v, vec.into());
+    }
+
+    #[test]
+    fn get_or_default((v, i) in arbitrary_rotated_instance_with_index(), offset: bool, x: u8) {
+        let index = if offset { i + v.len() } else { i };
+        let mut called = false;
+        match v.get(index) {
+            Some(&y) => {
+                prop_assert_eq!(*v.get_or(index, &x), y);
+                prop_assert_eq!(v.get_cloned_or(index, || { called = true; x }), y);
+                prop_assert!(!called);
+            }
+            None => {
+                prop_assert_eq!(*v.get_or(index, &x), x);
+                prop_assert_eq!(v.get_cloned_or(index, || { called = true; x }), x);
+                prop_assert!(called);
+            }
+        }
+    }
+
+    #[test]
+    fn insert_cursor((mut v, i) in arbitrary_rotated_instance_with_index(), xs: Vec<u8>) {
+        let mut expected = v.clone();
+        for (j, &x) in xs.iter().enumerate() {
+            expected.insert(i + j, x);
+        }
+        {
+            let mut cursor = v.insert_cursor(i);
+            for &x in &xs {
+                cursor.insert_next(x);
+            }
+        }
+        prop_assert_eq!(v, expected);
+    }
+
+    #[test]
+    fn compare_ord(v1 in arbitrary_rotated_instance(), v2 in arbitrary_rotated_instance()) {
+        let vec1: Vec<u8> = v1.clone().into();
+        let vec2: Vec<u8> = v2.clone().into();
+        prop_assert_eq!(v1.cmp(&v2), vec1.cmp(&vec2));
+        prop_assert_eq!(v1.partial_cmp(&v2), vec1.partial_cmp(&vec2));
+        prop_assert_eq!(v1.cmp(&v1.clone()), Ordering::Equal);
+    }
+
+    #[test]
+    fn compare_ord_prefix((v, i) in arbitrary_rotated_instance_with_index()) {
+        let prefix: RotatedVec<u8> = v.iter().cloned().take(i).collect();
+        let expected = if i < v.len() { Ordering::Less } else { Ordering::Equal };
+        prop_assert_eq!(prefix.cmp(&v), expected);
+        prop_assert_eq!(v.cmp(&prefix), expected.reverse());
+    }
+
+    #[test]
+    fn clear_and_shrink(mut v in arbitrary_rotated_instance(), x: u8) {
+        v.clear_and_shrink();
+        prop_assert_eq!(v.len(), 0);
+        prop_assert_eq!(v.capacity(), 0);
+        v.push(x);
+        prop_assert_eq!(v, vec![x].into());
+    }
+
+    #[test]
+    fn step_by(v in arbitrary_rotated_instance(), step in 1..10usize) {
+        let stepped: Vec<&u8> = v.iter().step_by(step).collect();
+        let expected: Vec<&u8> = (0..v.len()).step_by(step).map(|i| v.get(i).unwrap()).collect();
+        prop_assert_eq!(stepped, expected);
+    }
+
+    #[test]
+    fn size_hint_after_nth((v, i) in arbitrary_rotated_instance_with_index(), j: usize) {
+        let vec: Vec<u8> = v.clone().into();
+        let mut iter = v.iter();
+        let mut vec_iter = vec.iter();
+        prop_assert_eq!(iter.nth(i), vec_iter.nth(i));
+        prop_assert_eq!(iter.size_hint(), vec_iter.size_hint());
+        let j = j % (vec.len() + 1);
+        prop_assert_eq!(iter.nth_back(j), vec_iter.nth_back(j));
+        prop_assert_eq!(iter.len(), vec_iter.len());
+        prop_assert_eq!(iter.last(), vec_iter.clone().last());
+        prop_assert_eq!(iter.count(), vec_iter.count());
+    }
+
+    #[test]
+    fn append(mut v1 in arbitrary_rotated_instance(), mut v2 in arbitrary_rotated_instance()) {
+        let mut vec: Vec<u8> = v1.clone().into();
+        vec.extend(Vec::from(v2.clone()));
+        v1.append(&mut v2);
+        prop_assert!(v2.is_empty());
+        prop_assert_eq!(v1, vec.into());
+    }
+
+    #[test]
+    fn extend_rotated(mut v in arbitrary_rotated_instance(), xs: Vec<u8>) {
+        let mut vec: Vec<u8> = v.clone().into();
+        vec.extend(xs.iter().cloned());
+        v.extend(xs.iter().cloned());
+        prop_assert_eq!(v, vec.into());
+    }
+
+    #[test]
+    fn pair_mut((mut v, i) in arbitrary_rotated_instance_with_index(), j: usize) {
+        let mut vec: Vec<u8> = v.clone().into();
+        let j = j % (vec.len() + 1);
+        match v.pair_mut(i, j) {
+            Some((a, b)) => {
+                prop_assert!(i != j && i < vec.len() && j < vec.len());
+                prop_assert_eq!(*a, vec[i]);
+                prop_assert_eq!(*b, vec[j]);
+                *a = a.wrapping_add(1);
+                *b = b.wrapping_add(2);
+                vec[i] = vec[i].wrapping_add(1);
+                vec[j] = vec[j].wrapping_add(2);
+            }
+            None => prop_assert!(i == j || i >= vec.len() || j >= vec.len()),
+        }
+        prop_assert_eq!(v, vec.into());
+    }
+
+    #[test]
+    fn swap((mut v, i) in arbitrary_rotated_instance_with_index(), j: usize) {
+        let mut vec: Vec<u8> = v.clone().into();
+        if !vec.is_empty() {
+            let i = i % vec.len();
+            let j = j % vec.len();
+            v.swap(i, j);
+            vec.swap(i, j);
+        }
+        prop_assert_eq!(v, vec.into());
+    }
+
+    #[test]
+    fn truncate_front((mut v, i) in arbitrary_rotated_instance_with_index(), extra: bool) {
+        let mut vec: Vec<u8> = v.clone().into();
+        let n = if extra { i + 1 } else { i };
+        v.truncate_front(n);
+        vec.drain(..min(n, vec.len()));
+        prop_assert_eq!(v, vec.into());
+    }
+
+    #[test]
+    fn find_rfind(v in arbitrary_rotated_instance(), x: u8) {
+        let vec: Vec<u8> = v.clone().into();
+        prop_assert_eq!(v.find(|&y| y % 7 == x % 7), vec.iter().find(|&&y| y % 7 == x % 7));
+        prop_assert_eq!(v.rfind(|&y| y % 7 == x % 7), vec.iter().rev().find(|&&y| y % 7 == x % 7));
+        prop_assert_eq!(v.find(|_| false), None);
+        prop_assert_eq!(v.rfind(|_| false), None);
+    }
+
+    #[test]
+    fn insert_sorted(xs: Vec<u8>) {
+        let mut v = RotatedVec::new();
+        for &x in &xs {
+            let index = v.insert_sorted(x);
+            prop_assert_eq!(v[index], x);
+            prop_assert!(v.get(index + 1).is_none_or(|&y| y > x));
+        }
+        let mut vec = xs.clone();
+        vec.sort();
+        prop_assert_eq!(v, vec.into());
+    }
+
+    #[test]
+    fn checked_insert_remove((mut v, i) in arbitrary_rotated_instance_with_index(), offset: bool, x: u8) {
+        let mut vec: Vec<u8> = v.clone().into();
+        let index = if offset { i + v.len() + 1 } else { i };
+        if index <= vec.len() {
+            prop_assert_eq!(v.checked_insert(index, x), Ok(()));
+            vec.insert(index, x);
+            prop_assert_eq!(v.checked_remove(index), Some(x));
+            vec.remove(index);
+        } else {
+            prop_assert_eq!(v.checked_insert(index, x), Err(x));
+            prop_assert_eq!(v.checked_remove(index), None);
+        }
+        prop_assert_eq!(v.checked_remove(vec.len()), None);
+        prop_assert_eq!(v, vec.into());
+    }
+
+    #[test]
+    fn iter_from((v, i) in arbitrary_rotated_instance_with_index(), offset: bool) {
+        let start = if offset { i + v.len() } else { i };
+        let from: Vec<&u8> = v.iter_from(start).collect();
+        let skipped: Vec<&u8> = v.iter().skip(start).collect();
+        prop_assert_eq!(from, skipped);
+        prop_assert_eq!(v.iter_from(start).len(), v.len() - min(start, v.len()));
+    }
+
+    #[test]
+    fn concat(vecs: Vec<Vec<u8>>) {
+        let v = RotatedVec::concat(vecs.clone());
+        prop_assert_eq!(v, vecs.concat().into());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn io_write(mut v in arbitrary_rotated_instance(), x: u32, s: String) {
+        use std::io::Write;
+        let mut vec: Vec<u8> = v.clone().into();
+        write!(v, "{}:{}", x, s).unwrap();
+        v.flush().unwrap();
+        write!(vec, "{}:{}", x, s).unwrap();
+        prop_assert_eq!(v, vec.into());
+    }
+
+    #[test]
+    fn dedup_counted(mut v in arbitrary_rotated_instance()) {
+        // make runs likely
+        for x in v.iter_mut() {
+            *x %= 3;
+        }
+        let vec: Vec<u8> = v.clone().into();
+        let runs = v.dedup_counted();
+        let mut expected: Vec<(u8, usize)> = Vec::new();
+        for &x in &vec {
+            if expected.last().map(|&(y, _)| y) == Some(x) {
+                expected.last_mut().unwrap().1 += 1;
+            } else {
+                expected.push((x, 1));
+            }
+        }
+        prop_assert_eq!(runs.iter().map(|&(_, n)| n).sum::<usize>(), vec.len());
+        prop_assert_eq!(&runs, &expected);
+        let mut deduped = vec.clone();
+        deduped.dedup();
+        prop_assert_eq!(v, deduped.into());
+    }
+
+    #[test]
+    fn contiguous_runs(v in arbitrary_rotated_instance()) {
+        let vec: Vec<u8> = v.clone().into();
+        let runs: Vec<&[u8]> = v.contiguous_runs().collect();
+        prop_assert!(runs.len() <= 2 * v.subarray_count());
+        prop_assert!(runs.iter().all(|run| !run.is_empty()));
+        prop_assert_eq!(runs.concat(), vec);
+    }
+
+    #[test]
+    fn raw_parts_round_trip(v in arbitrary_rotated_instance()) {
+        let (data, start_indexes) = v.clone().into_raw_parts();
+        let rebuilt = RotatedVec::from_raw_parts_checked(data.clone(), start_indexes.clone()).unwrap();
+        prop_assert_eq!(&rebuilt, &v);
+        let rebuilt = unsafe { RotatedVec::from_raw_parts(data, start_indexes) };
+        prop_assert_eq!(&rebuilt, &v);
+    }
+
+    #[test]
+    fn raw_parts_checked_rejects_invalid(v in arbitrary_rotated_instance(), i: usize, x: usize) {
+        let (data, mut start_indexes) = v.into_raw_parts();
+        if !start_indexes.is_empty() {
+            let i = i % start_indexes.len();
+            start_indexes[i] = i + 1 + x % 10;
+            prop_assert!(RotatedVec::from_raw_parts_checked(data.clone(), start_indexes.clone()).is_err());
+            start_indexes.push(0);
+            prop_assert!(RotatedVec::from_raw_parts_checked(data, start_indexes).is_err());
+        }
+    }
+
+    #[test]
+    fn into_raw_parts_preserves_layout(v in arbitrary_rotated_instance()) {
+        let parts = v.clone().into_raw_parts();
+        let rebuilt = unsafe { RotatedVec::from_raw_parts(parts.0.clone(), parts.1.clone()) };
+        for i in 0..v.len() {
+            prop_assert_eq!(rebuilt.get(i), v.get(i));
+        }
+        prop_assert_eq!(rebuilt.into_raw_parts(), parts);
+    }
+
+    // every element moved by `insert`/`remove` must end up in exactly one place,
+    // so tag each one uniquely and account for all of them at the end
+    #[test]
+    fn insert_remove_no_duplicates(ops: Vec<(bool, usize)>) {
+        let mut v = RotatedVec::new();
+        let mut removed = Vec::new();
+        let mut next_tag = 0u32;
+        for (insert, i) in ops {
+            if insert || v.is_empty() {
+                v.insert(i % (v.len() + 1), next_tag);
+                next_tag += 1;
+            } else {
+                removed.push(v.remove(i % v.len()));
+            }
+        }
+        let mut tags: Vec<u32> = v.iter().cloned().chain(removed).collect();
+        tags.sort_unstable();
+        prop_assert!(tags.into_iter().eq(0..next_tag));
+    }
+
+    #[test]
+    fn normalize(mut v in arbitrary_rotated_instance()) {
+        let expected: Vec<u8> = v.iter().cloned().collect();
+        let subarray_count = v.subarray_count();
+        v.normalize();
+        prop_assert_eq!(v.as_logical_slice(), Some(&expected[..]));
+        prop_assert_eq!(v.subarray_count(), subarray_count);
+        let (data, start_indexes) = v.into_raw_parts();
+        prop_assert_eq!(&data, &expected);
+        prop_assert!(start_indexes.iter().all(|&pivot_offset| pivot_offset == 0));
+        prop_assert!(RotatedVec::from_raw_parts_checked(data, start_indexes).is_ok());
+    }
+
+    #[test]
+    fn remove_indices(mut v in arbitrary_rotated_instance(), mask: Vec<bool>) {
+        let mut expected: Vec<u8> = v.iter().cloned().collect();
+        let indices: Vec<usize> = mask.iter().take(v.len()).enumerate()
+            .filter(|&(_, &remove)| remove).map(|(i, _)| i).collect();
+        for &i in indices.iter().rev() {
+            expected.remove(i);
+        }
+        v.remove_indices(&indices);
+        prop_assert!(v.iter().eq(expected.iter()));
+    }
+
+    #[test]
+    fn binary_heap(xs: Vec<u8>) {
+        let mut heap = BinaryHeap::new();
+        let mut std_heap = std::collections::BinaryHeap::new();
+        for &x in &xs {
+            heap.push(x);
+            std_heap.push(x);
+            prop_assert_eq!(heap.peek(), std_heap.peek());
+        }
+        prop_assert_eq!(heap.len(), xs.len());
+        while let Some(x) = std_heap.pop() {
+            prop_assert_eq!(heap.pop(), Some(x));
+        }
+        prop_assert!(heap.is_empty());
+        prop_assert_eq!(heap.pop(), None);
+    }
+
+    #[test]
+    fn clone_into_vec(vs in prop::collection::vec(arbitrary_rotated_instance(), 1..8)) {
+        let max_len = vs.iter().map(|v| v.len()).max().unwrap();
+        let mut target = Vec::with_capacity(max_len);
+        let capacity = target.capacity();
+        for v in &vs {
+            v.clone_into_vec(&mut target);
+            prop_assert!(target.iter().eq(v.iter()));
+            prop_assert_eq!(target.capacity(), capacity);
+        }
+    }
+
+    #[test]
+    fn index_of_rindex_of(v in arbitrary_rotated_instance(), x: u8) {
+        let vec: Vec<u8> = v.clone().into();
+        let first = v.index_of(|&y| y % 7 == x % 7);
+        let last = v.rindex_of(|&y| y % 7 == x % 7);
+        prop_assert_eq!(first, vec.iter().position(|&y| y % 7 == x % 7));
+        prop_assert_eq!(last, vec.iter().rposition(|&y| y % 7 == x % 7));
+        for i in first.iter().chain(last.iter()) {
+            prop_assert_eq!(v.get(*i).unwrap() % 7, x % 7);
+        }
+        prop_assert_eq!(v.index_of(|_| false), None);
+        prop_assert_eq!(v.rindex_of(|_| false), None);
+    }
+
+    #[test]
+    fn starts_ends_with((v, i) in arbitrary_rotated_instance_with_index(), needle: Vec<u8>) {
+        let vec: Vec<u8> = v.clone().into();
+        prop_assert!(v.starts_with(&vec[..i]));
+        prop_assert!(v.ends_with(&vec[i..]));
+        prop_assert_eq!(v.starts_with(&needle), vec.starts_with(&needle));
+        prop_assert_eq!(v.ends_with(&needle), vec.ends_with(&needle));
+        let mut longer = vec.clone();
+        longer.push(0);
+        prop_assert!(!v.starts_with(&longer));
+        prop_assert!(!v.ends_with(&longer));
+    }
+
+    #[test]
+    fn map_in_place(mut v in arbitrary_rotated_instance()) {
+        let expected: Vec<u8> = v.iter().map(|x| x.wrapping_mul(2)).collect();
+        v.map_in_place(|x| x.wrapping_mul(2));
+        prop_assert!(v.iter().eq(expected.iter()));
+    }
+
+    #[test]
+    fn binary_search(mut v in arbitrary_rotated_instance(), x: u8) {
+        v.sort();
+        let vec: Vec<u8> = v.clone().into();
+        match v.binary_search(&x) {
+            Ok(i) => prop_assert_eq!(v[i], x),
+            Err(i) => prop_assert_eq!(Err(i), vec.binary_search(&x)),
+        }
+    }
+
+    #[test]
+    fn partition(v in arbitrary_rotated_instance()) {
+        let (expected_evens, expected_odds): (Vec<u8>, Vec<u8>) = v.iter().partition(|&&x| x % 2 == 0);
+        let (evens, odds) = v.partition(|&x| x % 2 == 0);
+        prop_assert!(evens.iter().eq(expected_evens.iter()));
+        prop_assert!(odds.iter().eq(expected_odds.iter()));
+    }
+
+    #[test]
+    fn into_iter_size_hint((v, i) in arbitrary_rotated_instance_with_index()) {
+        let expected: Vec<u8> = v.iter().cloned().collect();
+        let mut iter = v.into_iter();
+        prop_assert_eq!(iter.size_hint(), (expected.len(), Some(expected.len())));
+        for _ in 0..i {
+            iter.next();
+        }
+        prop_assert_eq!(iter.len(), expected.len() - i);
+        let rest: Vec<u8> = iter.collect();
+        prop_assert_eq!(&rest[..], &expected[i..]);
+    }
+
+    #[test]
+    fn retain(mut v in arbitrary_rotated_instance(), modulus in 1..5u8) {
+        let mut expected: Vec<u8> = v.iter().cloned().collect();
+        expected.retain(|&x| x % modulus != 0);
+        v.retain(|&x| x % modulus != 0);
+        prop_assert!(v.iter().eq(expected.iter()));
+    }
+
+    #[test]
+    fn retain_all_preserves_layout(mut v in arbitrary_rotated_instance()) {
+        let (data, start_indexes) = v.clone().into_raw_parts();
+        v.retain(|_| true);
+        prop_assert_eq!(v.into_raw_parts(), (data, start_indexes));
+    }
+
+    #[test]
+    fn slice_to_owned((v, i) in arbitrary_rotated_instance_with_index(), j: usize) {
+        let vec: Vec<u8> = v.clone().into();
+        let j = i + j % (v.len() - i + 1);
+        prop_assert_eq!(v.slice_to_owned(0..i), RotatedVec::from(&vec[..i]));
+        prop_assert_eq!(v.slice_to_owned(i..v.len()), RotatedVec::from(&vec[i..]));
+        prop_assert_eq!(v.slice_to_owned(i..j), RotatedVec::from(&vec[i..j]));
+    }
+
+    #[test]
+    fn range((v, i) in arbitrary_rotated_instance_with_index(), j: usize) {
+        let j = i + j % (v.len() - i + 1);
+        prop_assert!(v.range(i..j).eq((i..j).map(|k| v.get(k).unwrap())));
+        prop_assert!(v.range(i..j).rev().eq((i..j).rev().map(|k| v.get(k).unwrap())));
+        prop_assert_eq!(v.range(i..j).len(), j - i);
+        prop_assert!(v.range(..i).eq(v.iter().take(i)));
+        prop_assert!(v.range(i..).eq(v.iter_from(i)));
+    }
+
+    #[test]
+    fn copied(v in arbitrary_rotated_instance()) {
+        prop_assert_eq!(v.copied().collect::<Vec<u8>>(), Vec::from(v.clone()));
+        prop_assert!(v.copied().rev().eq(v.iter().rev().cloned()));
+    }
+
+    #[test]
+    fn swap_with_slice(mut v in arbitrary_rotated_instance(), seed: u8) {
+        let original: Vec<u8> = v.iter().cloned().collect();
+        let mut other: Vec<u8> = original.iter().map(|x| x.wrapping_add(seed)).collect();
+        let expected = other.clone();
+        v.swap_with_slice(&mut other);
+        prop_assert!(v.iter().eq(expected.iter()));
+        prop_assert_eq!(other, original);
+    }
+
+    #[test]
+    fn truncate((mut v, i) in arbitrary_rotated_instance_with_index(), x: u8) {
+        let mut vec: Vec<u8> = v.clone().into();
+        vec.truncate(i);
+        v.truncate(i);
+        prop_assert!(v.iter().eq(vec.iter()));
+        // the layout must still support inserts after truncation
+        v.insert(i / 2, x);
+        vec.insert(i / 2, x);
+        prop_assert!(v.iter().eq(vec.iter()));
+    }
+
+    #[test]
+    fn backing_ptr(mut v in arbitrary_rotated_instance()) {
+        // `get_real_index` is private, so recover each element's physical offset from its address
+        let ptr = v.as_backing_ptr();
+        let mut offsets: Vec<usize> = (0..v.len())
+            .map(|i| unsafe { (v.get(i).unwrap() as *const u8).offset_from(ptr) } as usize)
+            .collect();
+        for (i, &off) in offsets.iter().enumerate() {
+            prop_assert_eq!(unsafe { *ptr.add(off) }, v[i]);
+        }
+        offsets.sort_unstable();
+        prop_assert!(offsets.into_iter().eq(0..v.len()));
+        // once normalized, physical order is logical order
+        v.normalize();
+        let expected: Vec<u8> = v.iter().map(|&x| !x).collect();
+        let len = v.len();
+        let ptr = v.as_backing_mut_ptr();
+        for i in 0..len {
+            unsafe { *ptr.add(i) = !*ptr.add(i) };
+        }
+        prop_assert!(v.iter().eq(expected.iter()));
+    }
+
+    #[test]
+    fn dedup_sorted(mut v in arbitrary_sorted_rotated_instance()) {
+        // squeeze the values into a small range so there are many duplicates
+        v.map_in_place(|x| x / 32);
+        let mut expected: Vec<u8> = v.iter().cloned().collect();
+        expected.dedup();
+        v.dedup_sorted();
+        prop_assert!(v.iter().zip(v.iter().skip(1)).all(|(x, y)| x < y));
+        prop_assert!(v.iter().eq(expected.iter()));
+    }
+
+    #[test]
+    fn insert_all_sorted(mut v in arbitrary_sorted_rotated_instance(), items: Vec<u8>) {
+        let mut expected: Vec<u8> = v.iter().cloned().collect();
+        expected.extend_from_slice(&items);
+        expected.sort_unstable();
+        v.insert_all_sorted(items);
+        prop_assert!(v.iter().eq(expected.iter()));
+    }
+
+    #[test]
+    fn contains_all(v in arbitrary_rotated_instance(), items: Vec<u8>) {
+        let expected = items.iter().all(|x| v.iter().any(|y| y == x));
+        prop_assert_eq!(v.contains_all(&items), expected);
+        // every prefix of the contents is a subset
+        let subset: Vec<u8> = v.iter().take(items.len()).cloned().collect();
+        prop_assert!(v.contains_all(&subset));
+        prop_assert!(v.contains_all(&[]));
+        let mut sorted = v.clone();
+        sorted.sort();
+        prop_assert_eq!(sorted.contains_all_sorted(&items), expected);
+        prop_assert!(sorted.contains_all_sorted(&subset));
+        prop_assert!(sorted.contains_all_sorted(&[]));
+    }
+
+    #[test]
+    fn map(v in arbitrary_rotated_instance()) {
+        let f = |&x: &u8| u32::from(x) * 1000 + 1;
+        let mapped: RotatedVec<u32> = v.map(f);
+        prop_assert_eq!(mapped, v.iter().map(f).collect::<RotatedVec<_>>());
+    }
+
+    #[test]
+    fn insert_unique(items: Vec<u8>) {
+        let mut v = RotatedVec::new();
+        let mut expected = std::collections::BTreeSet::new();
+        for &x in &items {
+            match v.insert_unique(x) {
+                Ok(index) => {
+                    prop_assert!(expected.insert(x));
+                    prop_assert_eq!(v[index], x);
+                }
+                Err(index) => {
+                    prop_assert!(!expected.insert(x));
+                    prop_assert_eq!(v[index], x);
+                }
+            }
+            prop_assert_eq!(v.len(), expected.len());
+        }
+        prop_assert!(v.iter().eq(expected.iter()));
+    }
+
+    #[test]
+    fn iter_fold((v, i) in arbitrary_rotated_instance_with_index(), back in 0..8usize) {
+        let vec: Vec<u8> = v.iter().cloned().collect();
+        // start the fold partway through from both ends
+        let mut iter = v.iter();
+        let mut vec_iter = vec.iter();
+        for _ in 0..i {
+            prop_assert_eq!(iter.next(), vec_iter.next());
+        }
+        for _ in 0..back {
+            prop_assert_eq!(iter.next_back(), vec_iter.next_back());
+        }
+        let folded = iter.fold(Vec::new(), |mut acc, &x| { acc.push(x); acc });
+        prop_assert_eq!(&folded, &vec_iter.clone().cloned().collect::<Vec<_>>());
+        let mut visited = Vec::new();
+        iter.for_each(|&x| visited.push(x));
+        prop_assert_eq!(visited, folded);
+    }
+
+    #[test]
+    fn count_true_false(vec: Vec<bool>) {
+        let v = rotated_from(&vec);
+        prop_assert_eq!(v.count_true(), v.iter().filter(|&&b| b).count());
+        prop_assert_eq!(v.count_false(), v.iter().filter(|&&b| !b).count());
+    }
+
+    #[test]
+    fn extend_from_rotated(mut v1 in arbitrary_rotated_instance(), v2 in arbitrary_rotated_instance()) {
+        let mut expected: Vec<u8> = v1.iter().cloned().collect();
+        expected.extend(v2.iter());
+        let v2_before = v2.clone();
+        v1.extend_from_rotated(&v2);
+        prop_assert!(v1.iter().eq(expected.iter()));
+        prop_assert_eq!(v2, v2_before);
+    }
+
+    #[test]
+    fn cursor_get(v in arbitrary_rotated_instance(), jumps: Vec<usize>) {
+        let mut cursor = v.cursor();
+        // sweep forward, then backward, then jump around
+        for i in 0..=v.len() {
+            prop_assert_eq!(cursor.get(i), v.get(i));
+        }
+        for i in (0..=v.len()).rev() {
+            prop_assert_eq!(cursor.get(i), v.get(i));
+        }
+        for j in jumps {
+            let i = j % (v.len() + 2);
+            prop_assert_eq!(cursor.get(i), v.get(i));
+        }
+    }
+
+    #[test]
+    fn split_off((mut v, i) in arbitrary_rotated_instance_with_index()) {
+        let mut vec: Vec<u8> = v.iter().cloned().collect();
+        let other = v.split_off(i);
+        let vec_other = vec.split_off(i);
+        prop_assert!(v.iter().eq(vec.iter()));
+        prop_assert!(other.iter().eq(vec_other.iter()));
+    }
+
+    #[test]
+    fn iter_rfold((v, i) in arbitrary_rotated_instance_with_index()) {
+        let vec: Vec<u8> = v.iter().cloned().collect();
+        let mut iter = v.iter();
+        let mut vec_iter = vec.iter();
+        for _ in 0..i / 2 {
+            iter.next();
+            vec_iter.next();
+        }
+        let rfolded = iter.rfold(Vec::new(), |mut acc, &x| { acc.push(x); acc });
+        prop_assert_eq!(rfolded, vec_iter.rev().cloned().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn iter_short_circuit(v in arbitrary_rotated_instance(), x: u8) {
+        let vec: Vec<u8> = v.iter().cloned().collect();
+        let (mut iter, mut vec_iter) = (v.iter(), vec.iter());
+        let (mut calls, mut vec_calls) = (0, 0);
+        prop_assert_eq!(
+            iter.all(|&y| { calls += 1; y != x }),
+            vec_iter.all(|&y| { vec_calls += 1; y != x }));
+        prop_assert_eq!(calls, vec_calls);
+        prop_assert!(iter.eq(vec_iter));
+
+        let (mut iter, mut vec_iter) = (v.iter(), vec.iter());
+        prop_assert_eq!(iter.any(|&y| y == x), vec_iter.any(|&y| y == x));
+        prop_assert!(iter.eq(vec_iter));
+
+        let (mut iter, mut vec_iter) = (v.iter(), vec.iter());
+        prop_assert_eq!(iter.find(|&&y| y == x), vec_iter.find(|&&y| y == x));
+        prop_assert!(iter.eq(vec_iter));
+
+        let (mut iter, mut vec_iter) = (v.iter(), vec.iter());
+        prop_assert_eq!(iter.rfind(|&&y| y == x), vec_iter.rfind(|&&y| y == x));
+        prop_assert!(iter.eq(vec_iter));
+    }
+
+    #[test]
+    fn drain_into(mut v in arbitrary_rotated_instance(), mut sink: Vec<u8>) {
+        let mut expected = sink.clone();
+        expected.extend(v.iter());
+        let capacity = v.capacity();
+        v.drain_into(&mut sink);
+        prop_assert_eq!(sink, expected);
+        prop_assert!(v.is_empty());
+        prop_assert_eq!(v.capacity(), capacity);
+    }
+
+    // `get_real_index` trusts the offset array to end at the last subarray, so check every
+    // index against a shadow `Vec` after each operation that can change the layout
+    #[test]
+    fn get_matches_shadow_after_mixed_ops(ops in prop::collection::vec(any::<(u8, usize, u8)>(), 0..2000)) {
+        let mut v = RotatedVec::new();
+        let mut shadow: Vec<u8> = Vec::new();
+        for (op, i, x) in ops {
+            let len = shadow.len();
+            // growth outweighs shrinkage, so each run sweeps through lengths up to 255
+            match op % 12 {
+                0..=5 if len < 255 => {
+                    v.insert(i % (len + 1), x);
+                    shadow.insert(i % (len + 1), x);
+                }
+                6 if len < 255 => {
+                    v.push(x);
+                    shadow.push(x);
+                }
+                7 if len > 0 => {
+                    prop_assert_eq!(v.pop(), shadow.pop());
+                }
+                8 => {
+                    let new_len = len.saturating_sub(i % 4);
+                    v.truncate(new_len);
+                    shadow.truncate(new_len);
+                }
+                9 if len < 250 => {
+                    let mut other: RotatedVec<u8> = vec![x; i % 4].into();
+                    v.append(&mut other);
+                    shadow.extend(std::iter::repeat_n(x, i % 4));
+                }
+                10 => {
+                    let at = len.saturating_sub(i % 4);
+                    prop_assert!(v.split_off(at).iter().eq(shadow.split_off(at).iter()));
+                }
+                _ if len > 0 => {
+                    prop_assert_eq!(v.remove(i % len), shadow.remove(i % len));
+                }
+                _ => {}
+            }
+            prop_assert_eq!(v.len(), shadow.len());
+            for (j, x) in shadow.iter().enumerate() {
+                prop_assert_eq!(v.get(j), Some(x));
+            }
+            prop_assert_eq!(v.get(shadow.len()), None);
+        }
+        let (data, start_indexes) = v.into_raw_parts();
+        prop_assert!(RotatedVec::from_raw_parts_checked(data, start_indexes).is_ok());
+    }
+
+    #[test]
+    fn rotated_deque(ops: Vec<(u8, u8)>) {
+        let mut deque = RotatedDeque::new();
+        let mut std_deque = VecDeque::new();
+        for (op, x) in ops {
+            match op % 4 {
+                0 => {
+                    deque.push_front(x);
+                    std_deque.push_front(x);
+                }
+                1 => {
+                    deque.push_back(x);
+                    std_deque.push_back(x);
+                }
+                2 => prop_assert_eq!(deque.pop_front(), std_deque.pop_front()),
+                _ => prop_assert_eq!(deque.pop_back(), std_deque.pop_back()),
+            }
+            prop_assert_eq!(deque.front(), std_deque.front());
+            prop_assert_eq!(deque.back(), std_deque.back());
+            prop_assert_eq!(deque.len(), std_deque.len());
+            prop_assert_eq!(deque.is_empty(), std_deque.is_empty());
+        }
+    }
+
+    #[test]
+    fn rev_iter(v in arbitrary_rotated_instance()) {
+        let mut expected: Vec<u8> = v.iter().cloned().collect();
+        expected.reverse();
+        prop_assert_eq!(v.rev_iter().cloned().collect::<Vec<_>>(), expected.clone());
+        prop_assert_eq!(v.rev_iter().len(), v.len());
+        // still double-ended: reversing again restores logical order
+        expected.reverse();
+        prop_assert_eq!(v.rev_iter().rev().cloned().collect::<Vec<_>>(), expected);
+    }
+
+    #[test]
+    fn snapshot(v in arbitrary_rotated_instance()) {
+        let snapshot = v.snapshot();
+        prop_assert_eq!(&snapshot, &v);
+        let (data, start_indexes) = snapshot.into_raw_parts();
+        prop_assert!(data.iter().eq(v.iter()));
+        prop_assert_eq!(start_indexes.len(), v.subarray_count());
+        prop_assert!(start_indexes.iter().all(|&pivot_offset| pivot_offset == 0));
+    }
+
+    #[test]
+    fn grow_by(mut v in arbitrary_rotated_instance(), additional in 0..100usize, x: u8) {
+        let mut expected: Vec<u8> = v.iter().cloned().collect();
+        expected.resize(expected.len() + additional, x);
+        v.grow_by(additional, x);
+        prop_assert!(v.iter().eq(expected.iter()));
+        let (data, start_indexes) = v.into_raw_parts();
+        prop_assert!(RotatedVec::from_raw_parts_checked(data, start_indexes).is_ok());
+    }
+
+    #[test]
+    fn logical_enumerate(v in arbitrary_rotated_instance(), from_back: Vec<bool>) {
+        for (i, &x) in v.logical_enumerate().rev() {
+            prop_assert_eq!(v[i], x);
+        }
+        // alternating ends in any pattern must still visit each index exactly once
+        let mut iter = v.logical_enumerate();
+        let mut seen = vec![false; v.len()];
+        for back in from_back.into_iter().chain(std::iter::repeat(false)).take(v.len() + 1) {
+            match if back { iter.next_back() } else { iter.next() } {
+                Some((i, &x)) => {
+                    prop_assert_eq!(v[i], x);
+                    prop_assert!(!seen[i]);
+                    seen[i] = true;
+                }
+                None => break,
+            }
+        }
+        prop_assert!(seen.into_iter().all(|s| s));
+    }
+
+    #[test]
+    fn range_query(v in arbitrary_sorted_rotated_instance(), lo: u8, hi: u8) {
+        let expected: Vec<u8> = v.iter().cloned().filter(|x| lo <= *x && *x < hi).collect();
+        let view = v.range_query(&lo, &hi);
+        prop_assert_eq!(view.len(), expected.len());
+        prop_assert!(view.iter().eq(expected.iter()));
+    }
+
+    #[test]
+    fn eq_vec_deque(v in arbitrary_rotated_instance(), x: u8) {
+        let mut deque: VecDeque<u8> = v.iter().cloned().collect();
+        prop_assert_eq!(&deque, &v);
+        deque.push_back(x);
+        prop_assert_ne!(&deque, &v);
+        deque.pop_back();
+        if let Some(front) = deque.front_mut() {
+            *front = front.wrapping_add(1);
+            prop_assert_ne!(&deque, &v);
+        }
+    }
+
+    #[test]
+    fn truncate_while(v in arbitrary_rotated_instance(), zeros in 0..20usize) {
+        // squeeze the values so some trailing elements are zero already
+        let mut v: RotatedVec<u8> = v.iter().map(|&x| x % 4).collect();
+        v.push_front(1);
+        v.grow_by(zeros, 0);
+        let mut expected: Vec<u8> = v.iter().cloned().collect();
+        while expected.last() == Some(&0) {
+            expected.pop();
+        }
+        v.truncate_while(|&x| x == 0);
+        prop_assert!(v.iter().eq(expected.iter()));
+        prop_assert_ne!(v.iter().last(), Some(&0));
+    }
+
+    #[test]
+    fn real_indices(v in arbitrary_rotated_instance()) {
+        let real_indices: Vec<usize> = v.real_indices().collect();
+        prop_assert_eq!(real_indices.len(), v.len());
+        let (data, _) = v.clone().into_raw_parts();
+        for i in 0..v.len() {
+            prop_assert_eq!(&data[v.real_indices().nth(i).unwrap()], v.get(i).unwrap());
+        }
+        let mut sorted = real_indices;
+        sorted.sort_unstable();
+        prop_assert!(sorted.into_iter().eq(0..v.len()));
+    }
+
+    #[test]
+    fn char_buffer_to_string(edits: Vec<(usize, char)>) {
+        let mut buffer: RotatedVec<char> = RotatedVec::new();
+        let mut expected: Vec<char> = Vec::new();
+        for (i, c) in edits {
+            let i = i % (expected.len() + 1);
+            buffer.insert(i, c);
+            expected.insert(i, c);
+        }
+        prop_assert_eq!(buffer.to_string(), expected.into_iter().collect::<String>());
+    }
+
+    #[test]
+    fn chunks_mut(mut v in arbitrary_rotated_instance(), chunk_size in 1..10usize) {
+        let mut expected: Vec<u8> = v.iter().cloned().collect();
+        for (i, chunk) in expected.chunks_mut(chunk_size).enumerate() {
+            for x in chunk {
+                *x = x.wrapping_add(i as u8);
+            }
+        }
+        let chunks: Vec<Vec<&mut u8>> = v.chunks_mut(chunk_size).collect();
+        prop_assert_eq!(chunks.len(), expected.len().div_ceil(chunk_size));
+        // every chunk is alive at once, so mutating through each must touch distinct elements
+        for (i, chunk) in chunks.into_iter().enumerate() {
+            for x in chunk {
+                *x = x.wrapping_add(i as u8);
+            }
+        }
+        prop_assert!(v.iter().eq(expected.iter()));
+    }
+
+    #[test]
+    fn extend_from_refs(mut v in arbitrary_rotated_instance(), other: Vec<u8>) {
+        let mut expected: Vec<u8> = v.iter().cloned().collect();
+        expected.extend(other.iter());
+        v.extend(other.iter());
+        prop_assert!(v.iter().eq(expected.iter()));
+    }
+
+    #[test]
+    fn group_by_concatenates_to_original(v in arbitrary_rotated_instance(), modulus in 1..10u8) {
+        let groups: Vec<Vec<u8>> = v.group_by(|a, b| a % modulus == b % modulus).collect();
+        prop_assert!(groups.iter().flatten().eq(v.iter()));
+        for group in groups.iter() {
+            prop_assert!(!group.is_empty());
+            prop_assert!(group.iter().all(|x| x % modulus == group[0] % modulus));
+        }
+        for pair in groups.windows(2) {
+            prop_assert!(pair[0].last().unwrap() % modulus != pair[1][0] % modulus);
+        }
+    }
+}
+
+#[test]
+fn from_exact_size_iter() {
+    let len = 100_000;
+    let v: RotatedVec<u32> = (0..len).collect();
+    assert_eq!(v.capacity(), len as usize);
+    // the offset array is allocated at its final size too, with no spare capacity
+    let offsets_size = v.memory_usage() - v.capacity() * std::mem::size_of::<u32>();
+    assert_eq!(offsets_size, v.subarray_count() * std::mem::size_of::<usize>());
+    assert!(v.iter().cloned().eq(0..len));
+}
+
+#[test]
+fn from_fn_squares() {
+    let len = 1000;
+    let v = RotatedVec::from_fn(len, |i| i * i);
+    assert_eq!(v.len(), len);
+    for i in 0..len {
+        assert_eq!(v.get(i), Some(&(i * i)));
+    }
+}
+
+#[test]
+fn memory_usage() {
+    use std::mem::size_of;
+    let mut v: RotatedVec<u64> = RotatedVec::new();
+    assert_eq!(v.memory_usage(), 0);
+    let mut prev_usage = 0;
+    for i in 0..1000 {
+        v.push(i);
+        assert!(v.memory_usage() >= prev_usage);
+        assert!(v.memory_usage() >= v.len() * size_of::<u64>() + v.subarray_count() * size_of::<usize>());
+        prev_usage = v.memory_usage();
+    }
+    assert!(prev_usage > 0);
+    let v: RotatedVec<u64> = RotatedVec::with_capacity(55);
+    assert_eq!(v.memory_usage(), 55 * size_of::<u64>() + 10 * size_of::<usize>());
+}
+
+// counts its own drops, to check that no element is leaked or dropped twice
+#[derive(Debug)]
+struct DropCounter(usize, Rc<Cell<usize>>);
+
+impl Drop for DropCounter {
+    fn drop(&mut self) {
+        self.1.set(self.1.get() + 1);
+    }
+}
+
+impl PartialEq for DropCounter {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+// builds a vector of `DropCounter`s with the given values in logical order,
+// with each full subarray rotated by `pivot` modulo its length
+fn rotated_drop_counters<I>(values: I, pivot: usize, drops: &Rc<Cell<usize>>) -> RotatedVec<DropCounter>
+where
+    I: IntoIterator<Item = usize>,
+{
+    let mut data: Vec<usize> = values.into_iter().collect();
+    let len = data.len();
+    let mut start_indexes = Vec::new();
+    let mut subarray_start_idx = 0;
+    while subarray_start_idx < len {
+        let subarray_len = start_indexes.len() + 1;
+        let subarray_end_idx = subarray_start_idx + subarray_len;
+        let pivot_offset = if subarray_end_idx <= len { pivot % subarray_len } else { 0 };
+        data[subarray_start_idx..min(subarray_end_idx, len)].rotate_right(pivot_offset);
+        start_indexes.push(pivot_offset);
+        subarray_start_idx = subarray_end_idx;
+    }
+    let data = data.into_iter().map(|i| DropCounter(i, drops.clone())).collect();
+    RotatedVec::from_raw_parts_checked(data, start_indexes).unwrap()
+}
+
+#[test]
+fn split_off_moves_elements() {
+    for len in 0..30 {
+        for pivot in 0..4 {
+            for at in 0..=len {
+                let drops = Rc::new(Cell::new(0));
+                let mut v = rotated_drop_counters(0..len, pivot, &drops);
+                let other = v.split_off(at);
+                assert_eq!(drops.get(), 0);
+                let (v, other) = (Vec::from(v), Vec::from(other));
+                assert!(v.iter().map(|x| x.0).eq(0..at));
+                assert!(other.iter().map(|x| x.0).eq(at..len));
+                drop(v);
+                drop(other);
+                assert_eq!(drops.get(), len);
+            }
+        }
+    }
+}
+
+#[test]
+fn retain_drops_removed_elements_once() {
+    for len in 0..30 {
+        for pivot in 0..4 {
+            let drops = Rc::new(Cell::new(0));
+            let mut v = rotated_drop_counters(0..len, pivot, &drops);
+            v.retain(|x| x.0 % 3 != 0);
+            assert_eq!(drops.get(), len.div_ceil(3));
+            let v = Vec::from(v);
+            assert!(v.iter().map(|x| x.0).eq((0..len).filter(|i| i % 3 != 0)));
+            drop(v);
+            assert_eq!(drops.get(), len);
+        }
+    }
+}
+
+#[test]
+fn drain_into_moves_elements() {
+    for len in 0..30 {
+        for pivot in 0..4 {
+            let drops = Rc::new(Cell::new(0));
+            let mut v = rotated_drop_counters(0..len, pivot, &drops);
+            let mut sink = Vec::new();
+            v.drain_into(&mut sink);
+            assert_eq!(drops.get(), 0);
+            assert!(v.is_empty());
+            assert!(sink.iter().map(|x| x.0).eq(0..len));
+            drop(v);
+            drop(sink);
+            assert_eq!(drops.get(), len);
+        }
+    }
+}
+
+#[test]
+fn dedup_sorted_drops_repeats_once() {
+    for len in 0..30 {
+        for pivot in 0..4 {
+            let drops = Rc::new(Cell::new(0));
+            let mut v = rotated_drop_counters((0..len).map(|i| i / 2), pivot, &drops);
+            v.dedup_sorted();
+            assert_eq!(drops.get(), len / 2);
+            let v = Vec::from(v);
+            assert!(v.iter().map(|x| x.0).eq(0..len.div_ceil(2)));
+            drop(v);
+            assert_eq!(drops.get(), len);
+        }
+    }
+}
+
+#[test]
+fn remove_moves_out_removed_element() {
+    for len in 1..30 {
+        for pivot in 0..4 {
+            for index in 0..len {
+                let drops = Rc::new(Cell::new(0));
+                let mut v = rotated_drop_counters(0..len, pivot, &drops);
+                let removed = v.remove(index);
+                assert_eq!(removed.0, index);
+                assert_eq!(drops.get(), 0);
+                assert!((0..v.len()).map(|i| v.get(i).unwrap().0).eq((0..len).filter(|&i| i != index)));
+                drop(removed);
+                assert_eq!(drops.get(), 1);
+                drop(v);
+                assert_eq!(drops.get(), len);
+            }
+        }
+    }
+}
+
+#[test]
+fn min_max_by_key() {
+    #[derive(Clone, Copy, Debug, Default, PartialEq)]
+    struct Point {
+        id: u32,
+        weight: i64,
+    }
+    let mut v: RotatedVec<Point> = RotatedVec::new();
+    assert_eq!(v.min_by_key(|p| p.weight), None);
+    assert_eq!(v.max_by_key(|p| p.weight), None);
+    for (id, &weight) in [5, -2, 7, -2, 7, 0].iter().enumerate() {
+        v.push_front(Point { id: id as u32, weight });
+    }
+    // logical order is reversed, so ties resolve against the reversed ids
+    assert_eq!(v.min_by_key(|p| p.weight), Some(&Point { id: 3, weight: -2 }));
+    assert_eq!(v.max_by_key(|p| p.weight), Some(&Point { id: 2, weight: 7 }));
+    assert_eq!(v.max_by_key(|p| p.id), Some(&Point { id: 5, weight: 0 }));
+}
+
+#[test]
+#[should_panic]
+fn remove_indices_out_of_bounds() {
+    let mut v: RotatedVec<u8> = vec![1, 2, 3].into();
+    v.remove_indices(&[1, 3]);
+}
+
+#[test]
+fn hash_ignores_layout_different_inserts() {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+    let mut front: RotatedVec<u32> = RotatedVec::new();
+    for x in (0..100).rev() {
+        front.insert(0, x);
+    }
+    let back: RotatedVec<u32> = (0..100).collect();
+    assert_ne!(front.clone().into_raw_parts(), back.clone().into_raw_parts());
+    assert_eq!(front, back);
+    let mut front_hasher = DefaultHasher::new();
+    front.hash(&mut front_hasher);
+    let mut back_hasher = DefaultHasher::new();
+    back.hash(&mut back_hasher);
+    assert_eq!(front_hasher.finish(), back_hasher.finish());
+}
+
+#[test]
+fn extend_single_matches_push() {
+    let mut pushed = RotatedVec::new();
+    let mut extended = RotatedVec::new();
+    for i in 0..100u32 {
+        pushed.push_front(i);
+        extended.push_front(i);
+    }
+    for i in 0..100u32 {
+        pushed.push(i);
+        extended.extend(Some(i));
+        assert_eq!(pushed.clone().into_raw_parts(), extended.clone().into_raw_parts());
+    }
+}
+
+#[test]
+fn try_reserve() {
+    let mut v: RotatedVec<u64> = (0..10).collect();
+    assert!(v.try_reserve(100).is_ok());
+    assert!(v.capacity() >= 110);
+    assert!(v.try_reserve_exact(1000).is_ok());
+    assert!(v.capacity() >= 1010);
+    let capacity = v.capacity();
+    assert!(v.try_reserve(usize::MAX).is_err());
+    assert!(v.try_reserve_exact(usize::MAX / 2).is_err());
+    assert_eq!(v.capacity(), capacity);
+    assert!(v.iter().cloned().eq(0..10));
+}
+
+#[test]
+fn rotated_vec_macro() {
+    use rotated_vec::rotated_vec;
+    let empty: RotatedVec<u8> = rotated_vec![];
+    assert!(empty.is_empty());
+    let listed = rotated_vec![1u32, 2, 3, 4, 5,];
+    assert!(listed.iter().cloned().eq(1..=5));
+    let repeated = rotated_vec![7u8; 100];
+    assert_eq!(repeated.len(), 100);
+    assert!(repeated.iter().all(|&x| x == 7));
+    let none: RotatedVec<u8> = rotated_vec![7; 0];
+    assert!(none.is_empty());
+}
+
+#[test]
+fn nearest() {
+    let mut v: RotatedVec<i32> = RotatedVec::new();
+    assert_eq!(v.nearest(&0), None);
+    for x in (0..50).rev() {
+        v.push_front(x * 10);
+    }
+    // present values
+    assert_eq!(v.nearest(&0), Some(0));
+    assert_eq!(v.nearest(&250), Some(25));
+    // between elements, the lower one wins
+    assert_eq!(v.nearest(&251), Some(25));
+    assert_eq!(v.nearest(&259), Some(25));
+    // below and above all elements
+    assert_eq!(v.nearest(&-1), Some(0));
+    assert_eq!(v.nearest(&1000), Some(49));
+}
+
+#[test]
+fn into_iter_collect_preallocates() {
+    let len = 10_000;
+    let mut v: RotatedVec<u32> = RotatedVec::new();
+    for i in (0..len).rev() {
+        v.push_front(i);
+    }
+    let vec: Vec<u32> = v.into_iter().collect();
+    assert_eq!(vec.capacity(), len as usize);
+    assert!(vec.into_iter().eq(0..len));
+}
+
+#[test]
+#[should_panic]
+fn slice_to_owned_out_of_bounds() {
+    let v: RotatedVec<u8> = vec![1, 2, 3].into();
+    v.slice_to_owned(1..4);
+}
+
+#[test]
+#[should_panic]
+fn range_out_of_bounds() {
+    let v: RotatedVec<u8> = vec![1, 2, 3].into();
+    v.range(2..=3);
+}
+
+#[test]
+#[should_panic(expected = "maximum usize")]
+fn range_inclusive_to_usize_max() {
+    let v: RotatedVec<u8> = vec![1, 2, 3].into();
+    v.range(..=usize::MAX);
+}
+
+#[test]
+fn non_default_non_copy_elements() {
+    #[derive(Debug, PartialEq)]
+    struct Name(String);
+    let mut v: RotatedVec<Name> = RotatedVec::new();
+    assert!(v.is_empty());
+    for i in 0..20 {
+        v.push(Name(i.to_string()));
+    }
+    assert_eq!(v.len(), 20);
+    assert_eq!(v.get(7), Some(&Name("7".to_string())));
+    v.get_mut(7).unwrap().0.push('!');
+    assert_eq!(v.get(7), Some(&Name("7!".to_string())));
+    for i in (10..20).rev() {
+        assert_eq!(v.pop(), Some(Name(i.to_string())));
+    }
+    assert_eq!(v.len(), 10);
+    assert!(v.capacity() >= 10);
+    v.clear();
+    assert_eq!(v.pop(), None);
+    let v: RotatedVec<Name> = RotatedVec::with_capacity(5);
+    assert!(v.capacity() >= 5);
+    let v: RotatedVec<Name> = Default::default();
+    assert!(v.is_empty());
+}
+
+#[test]
+fn truncate_drops_removed_elements_once() {
+    for len in 0..30 {
+        for pivot in 0..4 {
+            for new_len in 0..=len {
+                let drops = Rc::new(Cell::new(0));
+                let mut v = rotated_drop_counters(0..len, pivot, &drops);
+                v.truncate(new_len);
+                assert_eq!(drops.get(), len - new_len);
+                assert_eq!(v.len(), new_len);
+                for i in 0..new_len {
+                    assert_eq!(v.get(i).unwrap().0, i);
+                }
+                drop(v);
+                assert_eq!(drops.get(), len);
+            }
+        }
+    }
+}
+
+// run under Miri to check that no two live mutable references alias:
+// `cargo +nightly miri test --test proptests iter_mut_both_ends`
+#[test]
+fn iter_mut_both_ends_never_alias() {
+    for len in 0..40u32 {
+        let mut v = RotatedVec::new();
+        for i in (0..len).rev() {
+            v.push_front(i);
+        }
+        {
+            let mut iter = v.iter_mut();
+            let mut refs = Vec::new();
+            let mut from_back = false;
+            loop {
+                let next = if from_back { iter.next_back() } else { iter.next() };
+                match next {
+                    Some(x) => refs.push(x),
+                    None => break,
+                }
+                from_back = !from_back;
+            }
+            assert!(iter.next().is_none());
+            assert!(iter.next_back().is_none());
+            assert_eq!(refs.len(), len as usize);
+            // all references are alive at once, so mutating through each must touch a distinct element
+            for x in refs {
+                *x += 100;
+            }
+        }
+        assert!(v.iter().cloned().eq(100..len + 100));
+    }
+}
+
+#[test]
+fn sum_product() {
+    let vec = [3i64, -1, 4, 1, -5, 9, 2, 6];
+    let v = rotated_from(&vec);
+    assert_eq!(v.sum::<i64>(), vec.iter().sum::<i64>());
+    assert_eq!(v.product::<i64>(), vec.iter().product::<i64>());
+    let empty: RotatedVec<i64> = RotatedVec::new();
+    assert_eq!(empty.sum::<i64>(), 0);
+    assert_eq!(empty.product::<i64>(), 1);
+}
+
+#[test]
+fn truncate_and_shrink_releases_offsets() {
+    let offset_bytes = |v: &RotatedVec<u32>| v.memory_usage() - v.capacity() * std::mem::size_of::<u32>();
+    let mut v: RotatedVec<u32> = (0..100_000).collect();
+    let large_offset_bytes = offset_bytes(&v);
+    v.truncate(10);
+    // plain truncation keeps all capacity
+    assert_eq!(offset_bytes(&v), large_offset_bytes);
+    v.truncate_and_shrink(10);
+    assert!(v.iter().cloned().eq(0..10));
+    assert!(v.capacity() < 100);
+    // 10 elements need only 4 subarrays
+    assert!(offset_bytes(&v) < 16 * std::mem::size_of::<usize>());
+    assert!(offset_bytes(&v) < large_offset_bytes / 10);
+}
+
+#[test]
+fn iter_fold_large() {
+    let len = 1_000_000u64;
+    // a single insertion at the front rotates every subarray
+    let mut v: RotatedVec<u64> = (1..len).collect();
+    v.push_front(0);
+    let vec: Vec<u64> = (0..len).collect();
+    let hash = |acc: u64, &x: &u64| acc.wrapping_mul(31).wrapping_add(x);
+    assert_eq!(v.iter().fold(0, hash), vec.iter().fold(0, hash));
+    assert_eq!(v.iter().sum::<u64>(), len * (len - 1) / 2);
+}
+
+#[test]
+fn approx_eq() {
+    let mut v: RotatedVec<f64> = (1..10).map(f64::from).collect();
+    v.push_front(0.0);
+    let mut close = v.clone();
+    close.map_in_place(|x| x + 0.0005);
+    let mut far = v.clone();
+    far[5] += 0.01;
+    assert!(v.approx_eq(&v, 0.0));
+    assert!(v.approx_eq(&close, 0.001));
+    assert!(!v.approx_eq(&close, 0.0001));
+    assert!(!v.approx_eq(&far, 0.001));
+    // lengths must match even if every shared element is close
+    let mut shorter = v.clone();
+    shorter.pop();
+    assert!(!v.approx_eq(&shorter, 1.0));
+}
+
+#[test]
+fn rev_iter_empty() {
+    let v: RotatedVec<u8> = RotatedVec::new();
+    let mut iter = v.rev_iter();
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+    assert_eq!(iter.len(), 0);
+}
+
+#[test]
+fn grow_by_empty() {
+    let mut v: RotatedVec<u32> = RotatedVec::new();
+    v.grow_by(0, 7);
+    assert!(v.is_empty());
+    v.grow_by(10, 7);
+    assert!(v.iter().eq([7; 10].iter()));
+    assert!(v.capacity() >= 10);
+}
+
+#[test]
+fn range_query_bounds() {
+    let mut v: RotatedVec<i32> = (1..20).map(|x| x * 10).collect();
+    v.push_front(0);
+    // `lo` is inclusive and `hi` is exclusive
+    assert!(v.range_query(&30, &60).iter().eq([30, 40, 50].iter()));
+    assert!(v.range_query(&25, &61).iter().eq([30, 40, 50, 60].iter()));
+    assert!(v.range_query(&-100, &1000).iter().eq(v.iter()));
+    // empty ranges
+    assert!(v.range_query(&31, &39).is_empty());
+    assert!(v.range_query(&60, &30).is_empty());
+    assert!(v.range_query(&50, &50).is_empty());
+    assert!(v.range_query(&500, &600).is_empty());
+    assert!(RotatedVec::<i32>::new().range_query(&0, &10).is_empty());
+}
+
+#[test]
+fn compact_scans_memory_sequentially() {
+    let len = 10_000;
+    let mut v: RotatedVec<u32> = RotatedVec::with_capacity(2 * len);
+    for i in 0..len as u32 {
+        v.insert(i as usize / 2, i);
+    }
+    let expected: Vec<u32> = v.iter().cloned().collect();
+    let offsets = |v: &RotatedVec<u32>| -> Vec<usize> {
+        let base = v.as_backing_ptr();
+        (0..v.len()).map(|i| unsafe { (v.get(i).unwrap() as *const u32).offset_from(base) } as usize).collect()
+    };
+    // repeated insertion in the middle leaves the layout rotated
+    assert!(offsets(&v).windows(2).any(|w| w[0] > w[1]));
+    v.compact();
+    assert!(v.iter().eq(expected.iter()));
+    assert!(offsets(&v).into_iter().eq(0..len));
+    assert!(v.capacity() < 2 * len);
+}
+
+#[test]
+fn truncate_while_all_or_nothing() {
+    let mut v: RotatedVec<u8> = vec![0, 0, 0].into();
+    v.truncate_while(|&x| x == 0);
+    assert!(v.is_empty());
+    v.truncate_while(|_| panic!("no elements to examine"));
+    let mut v: RotatedVec<u8> = vec![0, 0, 1].into();
+    v.truncate_while(|&x| x == 0);
+    assert_eq!(v, vec![0, 0, 1].into());
+}
+
+#[test]
+fn try_from_iter() {
+    let items: Vec<Result<u32, String>> = (0..100).map(Ok).collect();
+    let v = RotatedVec::try_from_iter(items).unwrap();
+    assert!(v.iter().cloned().eq(0..100));
+
+    // the first error is returned, and nothing after it is consumed
+    let mut consumed = 0;
+    let items = (0..100).map(|i| {
+        consumed += 1;
+        match i {
+            50 => Err(format!("bad item {}", i)),
+            60 => Err(format!("later bad item {}", i)),
+            _ => Ok(i),
+        }
+    });
+    assert_eq!(RotatedVec::<u32>::try_from_iter(items), Err("bad item 50".to_string()));
+    assert_eq!(consumed, 51);
+}
+
+#[test]
+fn binary_search_by_key_on_field() {
+    #[derive(Copy, Clone, Debug, Default, PartialEq)]
+    struct Record {
+        id: u32,
+        weight: u8,
+    }
+
+    // ids are sorted but have gaps and repeats; weights are unordered
+    let ids = [1, 3, 3, 4, 8, 9, 10, 15, 20, 21, 22, 30, 31];
+    let records: Vec<Record> = ids
+        .iter()
+        .enumerate()
+        .map(|(i, &id)| Record { id, weight: (i * 7 % 5) as u8 })
+        .collect();
+    let mut v = rotated_from(&records);
+    for id in 0..35 {
+        let expected = records.binary_search_by_key(&id, |r| r.id);
+        let actual = v.binary_search_by_key(&id, |r| r.id);
+        match actual {
+            Ok(i) => {
+                assert!(expected.is_ok());
+                assert_eq!(v[i].id, id);
+            }
+            Err(i) => {
+                assert_eq!(expected, Err(i));
+                // inserting at the returned point keeps the ids sorted
+                v.insert(i, Record { id, weight: 0 });
+                assert!(v.iter().zip(v.iter().skip(1)).all(|(a, b)| a.id <= b.id));
+                assert_eq!(v.remove(i).id, id);
+            }
+        }
+    }
+    assert_eq!(v.binary_search_by_key(&3, |r| r.id).map(|i| v[i].id), Ok(3));
+    assert_eq!(v.binary_search_by_key(&0, |r| r.id), Err(0));
+    assert_eq!(v.binary_search_by_key(&5, |r| r.id), Err(4));
+    assert_eq!(v.binary_search_by_key(&100, |r| r.id), Err(ids.len()));
+}
+
+#[test]
+fn group_by_equality() {
+    let items = [5u8, 5, 5, 1, 2, 2, 7, 7, 7, 7, 3, 5, 5, 0, 0, 0, 0, 0];
+    let v = rotated_from(&items);
+    let groups: Vec<Vec<u8>> = v.group_by(|a, b| a == b).collect();
+    assert_eq!(
+        groups,
+        vec![
+            vec![5, 5, 5],
+            vec![1],
+            vec![2, 2],
+            vec![7, 7, 7, 7],
+            vec![3],
+            vec![5, 5],
+            vec![0, 0, 0, 0, 0],
+        ]
+    );
+    assert_eq!(RotatedVec::<u8>::new().group_by(|a, b| a == b).next(), None);
+}
+
+#[test]
+fn construct_non_copy() {
+    let strings: Vec<String> = (0..10).map(|i| i.to_string()).collect();
+    let collected: RotatedVec<String> = strings.iter().cloned().collect();
+    let converted = RotatedVec::from(strings.clone());
+    let generated = RotatedVec::from_fn(10, |i| i.to_string());
+    let parsed: RotatedVec<String> =
+        RotatedVec::try_from_iter(strings.iter().map(|s| Ok::<_, ()>(s.clone()))).unwrap();
+    for v in [collected, converted, generated, parsed] {
+        assert_eq!(v.len(), strings.len());
+        for (i, s) in strings.iter().enumerate() {
+            assert_eq!(v.get(i), Some(s));
+        }
+        let (data, start_indexes) = v.into_raw_parts();
+        let rebuilt = RotatedVec::from_raw_parts_checked(data, start_indexes).unwrap();
+        assert_eq!(rebuilt.get(9), Some(&strings[9]));
+    }
 }
@@ -0,0 +1,12 @@
+#![cfg(feature = "rayon")]
+
+use rayon::prelude::*;
+use rotated_vec::RotatedVec;
+
+#[test]
+fn par_collect_range() {
+    let v: RotatedVec<u32> = (0..100_000u32).into_par_iter().map(|x| x * 3).collect();
+    let expected: RotatedVec<u32> = (0..100_000u32).map(|x| x * 3).collect();
+    assert_eq!(v, expected);
+    assert!(v.iter().cloned().eq((0..100_000u32).map(|x| x * 3)));
+}
@@ -0,0 +1,26 @@
+#![cfg(feature = "rand")]
+
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use rotated_vec::RotatedVec;
+
+#[test]
+fn shuffle_is_deterministic() {
+    let mut v1: RotatedVec<u32> = (0..1000).collect();
+    let mut v2 = RotatedVec::new();
+    for i in (0..1000).rev() {
+        v2.push_front(i);
+    }
+    v1.shuffle(&mut StdRng::seed_from_u64(42));
+    v2.shuffle(&mut StdRng::seed_from_u64(42));
+    assert_eq!(v1, v2);
+    assert_ne!(v1, (0..1000).collect());
+}
+
+#[test]
+fn shuffle_preserves_elements() {
+    let mut v: RotatedVec<u32> = (0..1000).collect();
+    v.shuffle(&mut StdRng::seed_from_u64(7));
+    v.sort();
+    assert_eq!(v, (0..1000).collect());
+}
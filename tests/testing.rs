@@ -0,0 +1,43 @@
+#![cfg(feature = "testing")]
+
+extern crate proptest;
+use self::proptest::prelude::*;
+use rotated_vec::RotatedVec;
+
+proptest! {
+    #[test]
+    fn force_pivot(vec: Vec<u8>, subarray_idx: usize, pivot_offset: usize) {
+        let mut v: RotatedVec<u8> = vec.clone().into();
+        if v.subarray_count() > 0 {
+            let subarray_idx = subarray_idx % v.subarray_count();
+            let subarray_len = v.subarray_len(subarray_idx).unwrap();
+            // a partially full last subarray can't be rotated
+            let pivot_offset = if subarray_len == subarray_idx + 1 {
+                pivot_offset % subarray_len
+            } else {
+                0
+            };
+            v.force_pivot(subarray_idx, pivot_offset);
+            for (i, x) in vec.iter().enumerate() {
+                prop_assert_eq!(v.get(i), Some(x));
+            }
+            v.insert(0, 0);
+            prop_assert_eq!(v.remove(0), 0);
+            prop_assert_eq!(v, vec.into());
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn force_pivot_out_of_bounds() {
+    let mut v: RotatedVec<u8> = vec![1, 2, 3, 4, 5, 6].into();
+    v.force_pivot(1, 2);
+}
+
+#[test]
+#[should_panic]
+fn force_pivot_partial_last_subarray() {
+    let mut v: RotatedVec<u8> = vec![1, 2, 3, 4, 5].into();
+    v.force_pivot(2, 1);
+}